@@ -0,0 +1,229 @@
+// File: common/answers.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use super::registry::Part;
+
+// The answer to one part of a day. Integers are kept as Integer when they fit in an i64
+// and as BigInteger otherwise so answers compare equal however they were made.
+#[derive(Debug, Clone)]
+pub enum Answer {
+	Integer(i64),
+	BigInteger(i128),
+	String(String),
+	// For parts without a puzzle, like the second part of the last day.
+	NotApplicable,
+}
+impl Answer {
+	fn integer(&self) -> Option<i128> {
+		match self {
+			Answer::Integer(n) => Some(*n as i128),
+			Answer::BigInteger(n) => Some(*n),
+			_ => None,
+		}
+	}
+}
+impl PartialEq for Answer {
+	fn eq(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Answer::String(a), Answer::String(b)) => a == b,
+			(Answer::NotApplicable, Answer::NotApplicable) => true,
+			(a, b) => a.integer().is_some() && a.integer() == b.integer(),
+		}
+	}
+}
+impl Eq for Answer {}
+impl fmt::Display for Answer {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Answer::Integer(n) => fmt::Display::fmt(n, f),
+			Answer::BigInteger(n) => fmt::Display::fmt(n, f),
+			Answer::String(s) => f.pad(s),
+			Answer::NotApplicable => f.pad("N/A"),
+		}
+	}
+}
+// "N/A" is NotApplicable, anything that parses as an integer is an integer and
+// everything else is a string.
+impl FromStr for Answer {
+	type Err = String;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		if s.is_empty() {
+			return Err(String::from("an answer can not be empty"));
+		}
+		Ok(match (s, s.parse::<i128>()) {
+			("N/A", _) => Answer::NotApplicable,
+			(_, Ok(n)) => Answer::from(n),
+			(s, Err(_)) => Answer::from(s),
+		})
+	}
+}
+macro_rules! integer_answer {
+	($($t:ty),*) => {$(
+		impl From<$t> for Answer {
+			fn from(n: $t) -> Self {
+				match (i64::try_from(n), i128::try_from(n)) {
+					(Ok(n), _) => Answer::Integer(n),
+					(_, Ok(n)) => Answer::BigInteger(n),
+					_ => Answer::String(n.to_string()),
+				}
+			}
+		}
+		impl PartialEq<$t> for Answer {
+			fn eq(&self, other: &$t) -> bool {
+				match i128::try_from(*other) {
+					Ok(other) => self.integer() == Some(other),
+					Err(_) => matches!(self, Answer::String(s) if s.parse() == Ok(*other)),
+				}
+			}
+		}
+	)*};
+}
+integer_answer!(u8, u16, i32, u32, i64, u64, usize, i128, u128);
+impl From<String> for Answer {
+	fn from(s: String) -> Self {
+		Answer::String(s)
+	}
+}
+impl From<&str> for Answer {
+	fn from(s: &str) -> Self {
+		Answer::String(s.to_string())
+	}
+}
+impl PartialEq<&str> for Answer {
+	fn eq(&self, other: &&str) -> bool {
+		matches!(self, Answer::String(s) if s == other)
+	}
+}
+
+// One known answer from an answers file.
+// Each line is "<input>,<year>/<day>,<part>,<answer>" and lines starting with '#' are comments.
+// The answer is everything after the third comma so it may contain commas itself.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expected {
+	pub input: String,
+	pub year: u16,
+	pub day: u8,
+	pub part: Part,
+	pub answer: Answer,
+}
+
+pub fn parse(contents: &str) -> Result<Vec<Expected>, String> {
+	contents
+		.lines()
+		.enumerate()
+		.filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+		.map(|(i, line)| {
+			parse_line(line).ok_or_else(|| {
+				format!(
+					"line {}: expected \"<input>,<year>/<day>,<part>,<answer>\" but found \"{}\"",
+					i + 1,
+					line
+				)
+			})
+		})
+		.collect()
+}
+fn parse_line(line: &str) -> Option<Expected> {
+	let mut columns = line.splitn(4, ',');
+	let input = columns.next()?.trim();
+	let (year, day) = columns.next()?.trim().split_once('/')?;
+	let (year, day) = (year.parse().ok()?, day.parse().ok()?);
+	let part = match columns.next()?.trim() {
+		"1" => Part::One,
+		"2" => Part::Two,
+		_ => return None,
+	};
+	let answer = columns.next()?.trim().parse().ok()?;
+	if input.is_empty() {
+		return None;
+	}
+	Some(Expected {
+		input: input.to_string(),
+		year,
+		day,
+		part,
+		answer,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	#[test]
+	fn parse_test() {
+		let answers = parse(
+			"# comment\ninputs,2020/21,2,mxmxvkd,sqjhc\n\nalice/day_1.txt, 2020/1, 1, 514579\n",
+		)
+		.unwrap();
+		assert_eq!(
+			answers,
+			vec![
+				Expected {
+					input: String::from("inputs"),
+					year: 2020,
+					day: 21,
+					part: Part::Two,
+					answer: Answer::from("mxmxvkd,sqjhc"),
+				},
+				Expected {
+					input: String::from("alice/day_1.txt"),
+					year: 2020,
+					day: 1,
+					part: Part::One,
+					answer: Answer::Integer(514579),
+				},
+			]
+		);
+		assert_eq!(
+			parse("inputs,2020/1,3,0").err().unwrap(),
+			"line 1: expected \"<input>,<year>/<day>,<part>,<answer>\" but found \"inputs,2020/1,3,0\""
+		);
+	}
+	#[test]
+	fn answer_test() {
+		assert_eq!("42".parse(), Ok(Answer::Integer(42)));
+		assert_eq!("-7".parse(), Ok(Answer::Integer(-7)));
+		assert_eq!(
+			"225850756401039000000".parse(),
+			Ok(Answer::BigInteger(225850756401039000000))
+		);
+		assert_eq!("N/A".parse(), Ok(Answer::NotApplicable));
+		assert_eq!("abc,d".parse(), Ok(Answer::from("abc,d")));
+		assert!("".parse::<Answer>().is_err());
+		assert_eq!(Answer::BigInteger(5), Answer::Integer(5));
+		assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+		assert_ne!(Answer::from("5"), Answer::Integer(5));
+		assert_ne!(Answer::NotApplicable, Answer::Integer(0));
+		assert_eq!(Answer::from(5usize), 5);
+		assert_eq!(
+			format!("{:<5}|{:>4}", Answer::Integer(12), Answer::NotApplicable),
+			"12   | N/A"
+		);
+	}
+}
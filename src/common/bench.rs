@@ -0,0 +1,209 @@
+// File: common/bench.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+use super::registry::{Entry, Part};
+use super::report::milliseconds;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Phase {
+	Parse,
+	Solve(Part),
+}
+impl Phase {
+	pub fn name(&self) -> &'static str {
+		match self {
+			Phase::Parse => "parse",
+			Phase::Solve(Part::One) => "part 1",
+			Phase::Solve(Part::Two) => "part 2",
+		}
+	}
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"parse" => Some(Phase::Parse),
+			"part 1" => Some(Phase::Solve(Part::One)),
+			"part 2" => Some(Phase::Solve(Part::Two)),
+			_ => None,
+		}
+	}
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Stats {
+	pub min: Duration,
+	pub median: Duration,
+	pub mean: Duration,
+	pub std_dev: Duration,
+	pub p95: Duration,
+}
+impl Stats {
+	pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+		if samples.is_empty() {
+			return None;
+		}
+		let mut sorted = samples.to_vec();
+		sorted.sort_unstable();
+		let n = sorted.len();
+
+		let median = if n.is_multiple_of(2) {
+			(sorted[n / 2 - 1] + sorted[n / 2]) / 2
+		} else {
+			sorted[n / 2]
+		};
+		let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+		let variance = sorted
+			.iter()
+			.map(|sample| (sample.as_secs_f64() - mean).powi(2))
+			.sum::<f64>()
+			/ n as f64;
+		// nearest rank
+		let p95 = sorted[((n as f64 * 0.95).ceil() as usize).max(1) - 1];
+
+		Some(Self {
+			min: sorted[0],
+			median,
+			mean: Duration::from_secs_f64(mean),
+			std_dev: Duration::from_secs_f64(variance.sqrt()),
+			p95,
+		})
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseStats {
+	pub year: u16,
+	pub day: u8,
+	pub phase: Phase,
+	pub stats: Stats,
+}
+
+// How many times each day is run, the warmup runs are not timed.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Runs {
+	pub iterations: usize,
+	pub warmup: usize,
+}
+
+impl fmt::Display for Runs {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"{} iterations after {} warmup",
+			self.iterations, self.warmup
+		)
+	}
+}
+
+// Runs a day warmup + iterations times and keeps the timings of the last iterations.
+// Stops at the first error since the timings of a failing day mean nothing.
+pub fn bench(
+	entry: &Entry,
+	input: &str,
+	parts: &[Part],
+	iterations: usize,
+	warmup: usize,
+) -> Result<Vec<PhaseStats>, String> {
+	let mut parse_samples = Vec::with_capacity(iterations);
+	let mut solve_samples = vec![Vec::with_capacity(iterations); parts.len()];
+	for i in 0..(warmup + iterations) {
+		let records = entry.run(input, parts);
+		for (record, samples) in records.iter().zip(solve_samples.iter_mut()) {
+			if let Err(e) = &record.answer {
+				return Err(e.clone());
+			}
+			if i >= warmup {
+				samples.push(record.solve);
+			}
+		}
+		if i >= warmup {
+			if let Some(record) = records.first() {
+				parse_samples.push(record.parse);
+			}
+		}
+	}
+
+	let phases = Some(Phase::Parse)
+		.into_iter()
+		.chain(parts.iter().map(|part| Phase::Solve(*part)));
+	let samples = Some(parse_samples).into_iter().chain(solve_samples);
+	Ok(phases
+		.zip(samples)
+		.filter_map(|(phase, samples)| {
+			Some(PhaseStats {
+				year: entry.year,
+				day: entry.day,
+				phase,
+				stats: Stats::from_samples(&samples)?,
+			})
+		})
+		.collect())
+}
+
+pub fn table_header() -> String {
+	format!(
+		"{:<5} {:<4} {:<7} {:>12} {:>12} {:>12} {:>12} {:>12}\n",
+		"year", "day", "phase", "min", "median", "mean", "std dev", "p95"
+	)
+}
+pub fn table_rows(stats: &[PhaseStats]) -> String {
+	let mut out = String::new();
+	for phase in stats.iter() {
+		writeln!(
+			out,
+			"{:<5} {:<4} {:<7} {:>12} {:>12} {:>12} {:>12} {:>12}",
+			phase.year,
+			phase.day,
+			phase.phase.name(),
+			milliseconds(phase.stats.min),
+			milliseconds(phase.stats.median),
+			milliseconds(phase.stats.mean),
+			milliseconds(phase.stats.std_dev),
+			milliseconds(phase.stats.p95)
+		)
+		.unwrap();
+	}
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	#[test]
+	fn stats_test() {
+		let samples = [5, 1, 4, 2, 3, 100]
+			.iter()
+			.map(|ms| Duration::from_millis(*ms))
+			.collect::<Vec<_>>();
+		let stats = Stats::from_samples(&samples).unwrap();
+		assert_eq!(stats.min, Duration::from_millis(1));
+		assert_eq!(stats.median, Duration::from_micros(3500));
+		assert_eq!(stats.mean.as_millis(), 19);
+		assert_eq!(stats.std_dev.as_millis(), 36);
+		assert_eq!(stats.p95, Duration::from_millis(100));
+		assert_eq!(Stats::from_samples(&[]), None);
+	}
+}
@@ -1,4 +1,4 @@
-// File: lib.rs
+// File: common/chinese_remainder_theorem.rs
 // Author: Jacob Guenther
 // Date: December 2020
 
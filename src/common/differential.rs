@@ -0,0 +1,133 @@
+// File: common/differential.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use std::any::Any;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+
+use super::answers::Answer;
+use super::example;
+use super::generator::{Rng, SEEDS, SIZES};
+use super::registry::{panic_message, Entry, Part};
+
+// The fast and reference answers to one part of an input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+	pub year: u16,
+	pub day: u8,
+	pub input: String,
+	pub part: Part,
+	pub fast: Result<Answer, String>,
+	pub reference: Result<Answer, String>,
+}
+impl Comparison {
+	// An input that can not be parsed or a part that panics is a failure too.
+	pub fn passed(&self) -> bool {
+		self.fast.is_ok() && self.fast == self.reference
+	}
+}
+
+// Solves the input both ways, catching panics so the other inputs still run.
+pub fn compare(
+	entry: &Entry,
+	name: &str,
+	input: Result<String, String>,
+	parts: &[Part],
+) -> Vec<Comparison> {
+	let parsed = input.and_then(|input| (entry.parse)(&input).map_err(|e| e.to_string()));
+	let solve = |solver: fn(&Entry, &dyn Any, Part) -> Answer, part: Part| match &parsed {
+		Ok(parsed) => {
+			panic::catch_unwind(AssertUnwindSafe(|| solver(entry, parsed.as_ref(), part)))
+				.map_err(|e| panic_message(e.as_ref()))
+		}
+		Err(e) => Err(e.clone()),
+	};
+	parts
+		.iter()
+		.map(|&part| Comparison {
+			year: entry.year,
+			day: entry.day,
+			input: name.to_string(),
+			part,
+			fast: solve(Entry::solve, part),
+			reference: solve(Entry::reference, part),
+		})
+		.collect()
+}
+
+// Compares the parts on each example that has an answer for them and then on the
+// generated input for every seed and size.
+pub fn compare_day(
+	entry: &Entry,
+	parts: &[Part],
+	on_comparison: &mut dyn FnMut(&Comparison),
+) -> Vec<Comparison> {
+	let mut comparisons = Vec::new();
+	let mut add = |new: Vec<Comparison>| {
+		for comparison in new {
+			on_comparison(&comparison);
+			comparisons.push(comparison);
+		}
+	};
+	for example in entry.examples.iter() {
+		let example_parts = parts
+			.iter()
+			.copied()
+			.filter(|part| match part {
+				Part::One => example.part_1.is_some(),
+				Part::Two => example.part_2.is_some(),
+			})
+			.collect::<Vec<_>>();
+		let path = Path::new(example::DIRECTORY).join(example.file_name(entry.year, entry.day));
+		let input = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e));
+		let name = format!("example {}", example.name);
+		add(compare(entry, &name, input, &example_parts));
+	}
+	for seed in SEEDS {
+		for &size in SIZES.iter() {
+			let input = (entry.generate)(&mut Rng::new(seed), size);
+			let name = format!("seed {} size {}", seed, size);
+			add(compare(entry, &name, Ok(input), parts));
+		}
+	}
+	comparisons
+}
+
+#[cfg(test)]
+pub fn assert_reference(entry: &Entry) {
+	for comparison in compare_day(entry, entry.reference_parts, &mut |_| ()) {
+		assert!(
+			comparison.passed(),
+			"{}/{} {} part {}: fast {:?} but reference {:?}",
+			comparison.year,
+			comparison.day,
+			comparison.input,
+			comparison.part.number(),
+			comparison.fast,
+			comparison.reference
+		);
+	}
+}
@@ -0,0 +1,105 @@
+// File: common/example.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use super::answers::Expected;
+use super::registry::{Entry, Part};
+
+pub const DIRECTORY: &str = "inputs/examples";
+
+// An example from a day's puzzle text. The input is read from
+// "inputs/examples/<year>/day_<day>_<name>.txt" and a part without a known answer is skipped.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Example {
+	pub name: &'static str,
+	pub part_1: Option<&'static str>,
+	pub part_2: Option<&'static str>,
+}
+impl Example {
+	pub const fn new(
+		name: &'static str,
+		part_1: Option<&'static str>,
+		part_2: Option<&'static str>,
+	) -> Self {
+		Self {
+			name,
+			part_1,
+			part_2,
+		}
+	}
+	pub fn file_name(&self, year: u16, day: u8) -> String {
+		format!("{}/day_{}_{}.txt", year, day, self.name)
+	}
+	pub fn expected(&self, year: u16, day: u8) -> Vec<Expected> {
+		Part::BOTH
+			.iter()
+			.zip([self.part_1, self.part_2].iter())
+			.filter_map(|(&part, answer)| {
+				answer.map(|answer| Expected {
+					input: self.file_name(year, day),
+					year,
+					day,
+					part,
+					answer: answer
+						.parse()
+						.unwrap_or_else(|e| panic!("Example {}: {}", self.name, e)),
+				})
+			})
+			.collect()
+	}
+}
+
+// Every known example answer for the entries and parts, in the same form as an answers file.
+pub fn expected<'a>(entries: impl Iterator<Item = &'a Entry>, parts: &[Part]) -> Vec<Expected> {
+	entries
+		.flat_map(|entry| {
+			entry
+				.examples
+				.iter()
+				.flat_map(move |e| e.expected(entry.year, entry.day))
+		})
+		.filter(|expected| parts.contains(&expected.part))
+		.collect()
+}
+
+#[cfg(test)]
+pub fn assert_examples(entry: &Entry) {
+	use super::runner;
+	use std::path::Path;
+
+	let (year, day) = (entry.year, entry.day);
+	let expected = expected(std::iter::once(entry), &Part::BOTH);
+	assert!(!expected.is_empty(), "{}/{} has no examples", year, day);
+	for check in runner::check_answers(&expected, Path::new(DIRECTORY), &mut |_| ()) {
+		assert!(
+			check.passed(),
+			"{}/{} {} part {}: expected {} but found {:?}",
+			year,
+			day,
+			check.expected.input,
+			check.expected.part.number(),
+			check.expected.answer,
+			check.actual
+		);
+	}
+}
@@ -0,0 +1,131 @@
+// File: common/generator.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use std::ops::RangeInclusive;
+
+#[cfg(test)]
+use super::registry::Entry;
+
+// Seeds and sizes every generator is tested with.
+pub const SEEDS: RangeInclusive<u64> = 0..=19;
+pub const SIZES: [usize; 3] = [1, 10, 100];
+
+// A small seeded random number generator so generated inputs can be reproduced from their seed.
+// https://prng.di.unimi.it/splitmix64.c
+#[derive(Debug, Clone)]
+pub struct Rng {
+	state: u64,
+}
+impl Rng {
+	pub fn new(seed: u64) -> Self {
+		Self { state: seed }
+	}
+	pub fn next_u64(&mut self) -> u64 {
+		self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+		let mut z = self.state;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+		z ^ (z >> 31)
+	}
+	// A number in 0..n, n must not be 0.
+	pub fn below(&mut self, n: usize) -> usize {
+		assert!(n > 0, "Rng::below(0)");
+		((self.next_u64() as u128 * n as u128) >> 64) as usize
+	}
+	pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+		range.start() + self.below(range.end() - range.start() + 1)
+	}
+	pub fn signed(&mut self, range: RangeInclusive<i64>) -> i64 {
+		range.start() + self.below((range.end() - range.start()) as usize + 1) as i64
+	}
+	pub fn chance(&mut self, percent: usize) -> bool {
+		self.below(100) < percent
+	}
+	pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+		&items[self.below(items.len())]
+	}
+	// Fisher-Yates
+	pub fn shuffle<T>(&mut self, items: &mut [T]) {
+		for i in (1..items.len()).rev() {
+			items.swap(i, self.below(i + 1));
+		}
+	}
+	// A random lowercase word.
+	pub fn word(&mut self, len: RangeInclusive<usize>) -> String {
+		(0..self.range(len))
+			.map(|_| (b'a' + self.below(26) as u8) as char)
+			.collect()
+	}
+}
+
+// Parses and solves an input from the day's generator for every seed and size.
+// Failures name the seed and size so the input can be generated again.
+#[cfg(test)]
+pub fn assert_generated(entry: &Entry) {
+	use std::panic::{self, AssertUnwindSafe};
+
+	for seed in SEEDS {
+		for &size in SIZES.iter() {
+			let context = format!("{}/{} seed {} size {}", entry.year, entry.day, seed, size);
+			let input = (entry.generate)(&mut Rng::new(seed), size);
+			let parsed =
+				(entry.parse)(&input).unwrap_or_else(|e| panic!("{}: {}\n{}", context, e, input));
+			if let Some(problem) = (entry.lint)(&input).first() {
+				panic!("{}: lint {}\n{}", context, problem, input);
+			}
+			for part in entry.generated_parts.iter() {
+				let solved =
+					panic::catch_unwind(AssertUnwindSafe(|| entry.solve(parsed.as_ref(), *part)));
+				if solved.is_err() {
+					panic!("{} part {} panicked\n{}", context, part.number(), input);
+				}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	#[test]
+	fn rng_test() {
+		let mut rng = Rng::new(7);
+		let first = (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>();
+		let mut rng = Rng::new(7);
+		assert_eq!((0..5).map(|_| rng.next_u64()).collect::<Vec<_>>(), first);
+
+		for _ in 0..1000 {
+			assert!(rng.below(3) < 3);
+			assert!((5..=7).contains(&rng.range(5..=7)));
+			assert!((-2..=2).contains(&rng.signed(-2..=2)));
+		}
+		let mut items = (0..10).collect::<Vec<_>>();
+		rng.shuffle(&mut items);
+		items.sort_unstable();
+		assert_eq!(items, (0..10).collect::<Vec<_>>());
+		assert!(!rng.chance(0));
+		assert!(rng.chance(100));
+	}
+}
@@ -23,23 +23,42 @@ IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
 
+use std::collections::HashSet;
 use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::slice;
 
+use super::parse_error::ParseError;
 use super::vec2::*;
 
 pub trait GridT<T> {
 	fn new(x: usize, y: usize, default: &T) -> Self;
 	fn get(&self, x: usize, y: usize) -> Option<&T>;
+	fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T>;
+	fn get_i(&self, x: isize, y: isize) -> Option<&T>;
 	fn set(&mut self, x: usize, y: usize, value: &T);
 
-	fn adjacent(&self, x: usize, y: usize) -> Vec<(usize, usize)>;
-	fn diagonal_adjacent(&self, x: usize, y: usize) -> Vec<(usize, usize)>;
+	fn adjacent(&self, x: usize, y: usize) -> Adjacent<'static>;
+	fn diagonal_adjacent(&self, x: usize, y: usize) -> Adjacent<'static>;
+	fn adjacent_by<'a>(&self, x: usize, y: usize, offsets: &'a [(isize, isize)]) -> Adjacent<'a>;
+	fn adjacent_cells(&self, x: usize, y: usize) -> AdjacentCells<'_, T>;
+	fn diagonal_adjacent_cells(&self, x: usize, y: usize) -> AdjacentCells<'_, T>;
+	fn adjacent_cells_by<'a>(
+		&'a self,
+		x: usize,
+		y: usize,
+		offsets: &'a [(isize, isize)],
+	) -> AdjacentCells<'a, T>;
 
+	fn transform(&mut self, transform: Transform);
+	fn transformed(&self, transform: Transform) -> Self;
+	fn view(&self, transform: Transform) -> View<'_, T>;
+	fn orientations(&self) -> Orientations<'_, T>;
 	fn rotate(&mut self);
 	fn flip(&mut self, flip_direction: FlipDirection);
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
 	pub size: Vec2<usize>,
 	pub data: Vec<T>,
@@ -49,6 +68,114 @@ impl<T> Grid<T> {
 	pub fn index(&self, x: usize, y: usize) -> usize {
 		y * self.size.x + x
 	}
+	#[inline(always)]
+	pub fn contains(&self, x: usize, y: usize) -> bool {
+		x < self.size.x && y < self.size.y
+	}
+	// A grid written as lines with a byte for each cell, where every line must be as wide
+	// as the first. A byte parse_cell rejects is reported as unexpected.
+	pub fn parse<E>(
+		year: u16,
+		day: u8,
+		input: &str,
+		parse_cell: impl Fn(u8) -> Result<T, E>,
+	) -> Result<Self, ParseError> {
+		let width = input.lines().next().map_or(0, str::len);
+		let mut data = Vec::with_capacity(input.len());
+		let mut height = 0;
+		for line in input.lines() {
+			for (x, byte) in line.bytes().enumerate() {
+				data.push(parse_cell(byte).map_err(|_| unexpected(year, day, input, line, x))?);
+			}
+			if line.len() != width {
+				return Err(wrong_width(year, day, input, line, width));
+			}
+			height += 1;
+		}
+		Ok(Self {
+			size: Vec2::new(width, height),
+			data,
+		})
+	}
+	// Every problem parse would find instead of just the first.
+	pub fn problems<E>(
+		year: u16,
+		day: u8,
+		input: &str,
+		parse_cell: impl Fn(u8) -> Result<T, E>,
+	) -> Vec<ParseError> {
+		let width = input.lines().next().map_or(0, str::len);
+		let mut problems = Vec::new();
+		for line in input.lines() {
+			if line.len() != width {
+				problems.push(wrong_width(year, day, input, line, width));
+			}
+			for (x, byte) in line.bytes().enumerate() {
+				if line.is_char_boundary(x) && parse_cell(byte).is_err() {
+					problems.push(unexpected(year, day, input, line, x));
+				}
+			}
+		}
+		problems
+	}
+	// One line for each row with render_cell's char for each cell, the way parse reads it.
+	pub fn render(&self, render_cell: impl Fn(&T) -> char) -> String {
+		let mut out = String::with_capacity((self.size.x + 1) * self.size.y);
+		for row in self.data.chunks(self.size.x.max(1)) {
+			out.extend(row.iter().map(&render_cell));
+			out.push('\n');
+		}
+		out
+	}
+}
+fn unexpected(year: u16, day: u8, input: &str, line: &str, x: usize) -> ParseError {
+	let unexpected = line[x..].chars().next().unwrap_or(' ');
+	ParseError::at(
+		year,
+		day,
+		input,
+		&line[x..],
+		format!("unexpected '{}'", unexpected),
+	)
+}
+fn wrong_width(year: u16, day: u8, input: &str, line: &str, width: usize) -> ParseError {
+	ParseError::at(
+		year,
+		day,
+		input,
+		line,
+		format!("expected {} bytes but found {}", width, line.len()),
+	)
+}
+impl<T> Index<(usize, usize)> for Grid<T> {
+	type Output = T;
+	#[inline(always)]
+	fn index(&self, (x, y): (usize, usize)) -> &T {
+		assert!(
+			self.contains(x, y),
+			"({}, {}) is outside a {}x{} grid",
+			x,
+			y,
+			self.size.x,
+			self.size.y
+		);
+		&self.data[Grid::index(self, x, y)]
+	}
+}
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+	#[inline(always)]
+	fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+		assert!(
+			self.contains(x, y),
+			"({}, {}) is outside a {}x{} grid",
+			x,
+			y,
+			self.size.x,
+			self.size.y
+		);
+		let i = Grid::index(self, x, y);
+		&mut self.data[i]
+	}
 }
 impl<T> GridT<T> for Grid<T>
 where
@@ -65,117 +192,173 @@ where
 			data,
 		}
 	}
+	// None when the position is outside the grid.
 	#[inline(always)]
 	fn get(&self, x: usize, y: usize) -> Option<&T> {
-		assert!(x < self.size.x);
-		assert!(y < self.size.y);
+		if !self.contains(x, y) {
+			return None;
+		}
 		self.data.get(self.index(x, y))
 	}
 	#[inline(always)]
-	fn set(&mut self, x: usize, y: usize, value: &T) {
+	fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+		if !self.contains(x, y) {
+			return None;
+		}
 		let i = self.index(x, y);
-		self.data[i] = value.clone();
-	}
-	fn adjacent(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
-		let a = &[(-1, 0), (0, -1), (0, 1), (1, 0)];
-		let x_i = x as i32;
-		let y_i = y as i32;
-		let size_i = Vec2::<i32>::new(self.size.x as i32, self.size.y as i32);
-		let mut adjacent = Vec::new();
-		for (dx, dy) in a.iter() {
-			let new_x = dx + x_i;
-			let new_y = dy + y_i;
-			if new_x >= 0 && new_x < size_i.x && new_y >= 0 && new_y < size_i.y {
-				adjacent.push((new_x as usize, new_y as usize));
-			}
+		self.data.get_mut(i)
+	}
+	// Negative positions are outside the grid too, so neighbours can be found by adding offsets.
+	#[inline(always)]
+	fn get_i(&self, x: isize, y: isize) -> Option<&T> {
+		if x < 0 || y < 0 {
+			return None;
 		}
-		adjacent
-	}
-	fn diagonal_adjacent(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
-		let a = &[
-			(-1, -1),
-			(-1, 0),
-			(-1, 1),
-			(0, -1),
-			(0, 1),
-			(1, -1),
-			(1, 0),
-			(1, 1),
-		];
-		let x_i = x as i32;
-		let y_i = y as i32;
-		let size_i = Vec2::<i32>::new(self.size.x as i32, self.size.y as i32);
-		let mut adjacent = Vec::new();
-		for (dx, dy) in a.iter() {
-			let new_x = dx + x_i;
-			let new_y = dy + y_i;
-			if new_x >= 0 && new_x < size_i.x && new_y >= 0 && new_y < size_i.y {
-				adjacent.push((new_x as usize, new_y as usize));
-			}
+		self.get(x as usize, y as usize)
+	}
+	#[inline(always)]
+	fn set(&mut self, x: usize, y: usize, value: &T) {
+		self[(x, y)] = value.clone();
+	}
+	fn adjacent(&self, x: usize, y: usize) -> Adjacent<'static> {
+		self.adjacent_by(x, y, &ADJACENT)
+	}
+	fn diagonal_adjacent(&self, x: usize, y: usize) -> Adjacent<'static> {
+		self.adjacent_by(x, y, &DIAGONAL_ADJACENT)
+	}
+	fn adjacent_by<'a>(&self, x: usize, y: usize, offsets: &'a [(isize, isize)]) -> Adjacent<'a> {
+		Adjacent {
+			size: self.size,
+			x: x as isize,
+			y: y as isize,
+			offsets: offsets.iter(),
+		}
+	}
+	fn adjacent_cells(&self, x: usize, y: usize) -> AdjacentCells<'_, T> {
+		self.adjacent_cells_by(x, y, &ADJACENT)
+	}
+	fn diagonal_adjacent_cells(&self, x: usize, y: usize) -> AdjacentCells<'_, T> {
+		self.adjacent_cells_by(x, y, &DIAGONAL_ADJACENT)
+	}
+	fn adjacent_cells_by<'a>(
+		&'a self,
+		x: usize,
+		y: usize,
+		offsets: &'a [(isize, isize)],
+	) -> AdjacentCells<'a, T> {
+		AdjacentCells {
+			grid: self,
+			adjacent: self.adjacent_by(x, y, offsets),
 		}
-		adjacent
 	}
 
-	fn rotate(&mut self) {
-		assert_eq!(self.size.x, self.size.y);
-		let size = self.size.x;
-		for cycle in 0..(size / 2) {
-			let a = cycle;
-			let b = size - cycle - 1;
-			for element in a..b {
-				let c = element - a;
-				let top = self.get(a, element).unwrap().clone();
-				let right = self.get(element, b).unwrap().clone();
-				let bottom = self.get(b, b - c).unwrap().clone();
-				let left = self.get(b - c, a).unwrap().clone();
-
-				self.set(a, element, &right);
-				self.set(element, b, &bottom);
-				self.set(b, b - c, &left);
-				self.set(b - c, a, &top);
-			}
+	fn transform(&mut self, transform: Transform) {
+		*self = self.transformed(transform);
+	}
+	fn transformed(&self, transform: Transform) -> Self {
+		let view = self.view(transform);
+		let data = (0..view.size.y)
+			.flat_map(|y| (0..view.size.x).map(move |x| (x, y)))
+			.map(|(x, y)| view[(x, y)].clone())
+			.collect();
+		Self {
+			size: view.size,
+			data,
 		}
 	}
-	fn flip(&mut self, flip_direction: FlipDirection) {
-		match flip_direction {
-			FlipDirection::Horizontal => {
-				for y in 0..self.size.y / 2 {
-					let opposite = self.size.y - y - 1;
-					for x in 0..self.size.x {
-						let top = self.get(x, y).unwrap().clone();
-						let bottom = self.get(x, opposite).unwrap().clone();
-						self.set(x, y, &bottom);
-						self.set(x, opposite, &top);
-					}
-				}
-			}
-			FlipDirection::Vertical => {
-				for x in 0..self.size.x / 2 {
-					let opposite = self.size.x - x - 1;
-					for y in 0..self.size.y {
-						let top = self.get(x, y).unwrap().clone();
-						let bottom = self.get(opposite, y).unwrap().clone();
-						self.set(x, y, &bottom);
-						self.set(opposite, y, &top);
-					}
-				}
-			}
+	fn view(&self, transform: Transform) -> View<'_, T> {
+		let (x, y) = transform.size((self.size.x, self.size.y));
+		View {
+			grid: self,
+			inverse: transform.inverse(),
+			size: Vec2::new(x, y),
 		}
 	}
+	fn orientations(&self) -> Orientations<'_, T> {
+		Orientations {
+			grid: self,
+			transforms: Transform::ALL.iter(),
+		}
+	}
+	// Turns a quarter turn clockwise.
+	fn rotate(&mut self) {
+		self.transform(Transform::ROTATE);
+	}
+	fn flip(&mut self, flip_direction: FlipDirection) {
+		self.transform(flip_direction.into());
+	}
 }
+// Each row on its own line with a space between cells.
 impl<T> fmt::Display for Grid<T>
 where
-	T: Clone + fmt::Display,
+	T: fmt::Display,
 {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		let mut s = String::new();
-		for y in 0..self.size.x {
-			for x in 0..self.size.y {
-				s = format!("{}{} ", s, self.get(x, y).unwrap().clone());
+		for y in 0..self.size.y {
+			for x in 0..self.size.x {
+				if x > 0 {
+					write!(f, " ")?;
+				}
+				write!(f, "{}", self[(x, y)])?;
 			}
-			s = format!("{}\n", s);
+			writeln!(f)?;
 		}
-		write!(f, "{}", s)
+		Ok(())
+	}
+}
+
+// Up, down, left and right.
+pub const ADJACENT: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+// ADJACENT and the four corners.
+pub const DIAGONAL_ADJACENT: [(isize, isize); 8] = [
+	(-1, -1),
+	(-1, 0),
+	(-1, 1),
+	(0, -1),
+	(0, 1),
+	(1, -1),
+	(1, 0),
+	(1, 1),
+];
+
+// The positions at each offset from a position that are inside the grid.
+#[derive(Debug, Clone)]
+pub struct Adjacent<'a> {
+	size: Vec2<usize>,
+	x: isize,
+	y: isize,
+	offsets: slice::Iter<'a, (isize, isize)>,
+}
+impl Iterator for Adjacent<'_> {
+	type Item = (usize, usize);
+	fn next(&mut self) -> Option<Self::Item> {
+		for (dx, dy) in self.offsets.by_ref() {
+			let (x, y) = (self.x + dx, self.y + dy);
+			if x >= 0 && y >= 0 && (x as usize) < self.size.x && (y as usize) < self.size.y {
+				return Some((x as usize, y as usize));
+			}
+		}
+		None
+	}
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.offsets.len()))
+	}
+}
+
+// Adjacent positions along with what the grid holds there.
+#[derive(Debug, Clone)]
+pub struct AdjacentCells<'a, T> {
+	grid: &'a Grid<T>,
+	adjacent: Adjacent<'a>,
+}
+impl<'a, T> Iterator for AdjacentCells<'a, T> {
+	type Item = ((usize, usize), &'a T);
+	fn next(&mut self) -> Option<Self::Item> {
+		let (x, y) = self.adjacent.next()?;
+		Some(((x, y), &self.grid.data[self.grid.index(x, y)]))
+	}
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.adjacent.size_hint()
 	}
 }
 
@@ -200,14 +383,503 @@ pub enum Rotation {
 	R180,
 	R270,
 }
-impl Iterator for Rotation {
-	type Item = Rotation;
-	fn next(&mut self) -> Option<Self::Item> {
+impl Rotation {
+	pub const ALL: [Rotation; 4] = [Rotation::R0, Rotation::R90, Rotation::R180, Rotation::R270];
+
+	// The rotation a quarter turn further clockwise.
+	pub fn turned(&self) -> Rotation {
 		match self {
-			Rotation::R0 => Some(Rotation::R90),
-			Rotation::R90 => Some(Rotation::R180),
-			Rotation::R180 => Some(Rotation::R270),
-			Rotation::R270 => Some(Rotation::R0),
+			Rotation::R0 => Rotation::R90,
+			Rotation::R90 => Rotation::R180,
+			Rotation::R180 => Rotation::R270,
+			Rotation::R270 => Rotation::R0,
 		}
 	}
 }
+
+// One of the 8 ways to turn and flip a grid. The grid is flipped left to right first
+// when flipped and then turned a quarter turn clockwise for every turn, so a grid with
+// an odd number of turns swaps its width and height.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Transform {
+	turns: u8,
+	flipped: bool,
+}
+impl Transform {
+	pub const IDENTITY: Transform = Transform::new(0, false);
+	pub const ROTATE: Transform = Transform::new(1, false);
+	// Ordered by turns and then flipped, so the transform at index i turns i / 2 times
+	// and flips when i is odd.
+	pub const ALL: [Transform; 8] = [
+		Transform::new(0, false),
+		Transform::new(0, true),
+		Transform::new(1, false),
+		Transform::new(1, true),
+		Transform::new(2, false),
+		Transform::new(2, true),
+		Transform::new(3, false),
+		Transform::new(3, true),
+	];
+
+	pub const fn new(turns: u8, flipped: bool) -> Self {
+		Self {
+			turns: turns % 4,
+			flipped,
+		}
+	}
+	pub fn all() -> impl Iterator<Item = Transform> {
+		Self::ALL.iter().copied()
+	}
+	pub fn turns(&self) -> u8 {
+		self.turns
+	}
+	pub fn flipped(&self) -> bool {
+		self.flipped
+	}
+	// This transform followed by the other one.
+	pub fn then(&self, other: Transform) -> Transform {
+		// turning after a flip goes the other way round
+		let turns = if other.flipped {
+			other.turns + 4 - self.turns
+		} else {
+			other.turns + self.turns
+		};
+		Transform::new(turns, self.flipped != other.flipped)
+	}
+	pub fn inverse(&self) -> Transform {
+		if self.flipped {
+			*self
+		} else {
+			Transform::new(4 - self.turns, false)
+		}
+	}
+	// The width and height of a width x height grid after the transform.
+	pub fn size(&self, (width, height): (usize, usize)) -> (usize, usize) {
+		if self.turns.is_multiple_of(2) {
+			(width, height)
+		} else {
+			(height, width)
+		}
+	}
+	// Where a cell of a width x height grid ends up.
+	pub fn apply(&self, (x, y): (usize, usize), (width, height): (usize, usize)) -> (usize, usize) {
+		let x = if self.flipped { width - 1 - x } else { x };
+		match self.turns {
+			0 => (x, y),
+			1 => (height - 1 - y, x),
+			2 => (width - 1 - x, height - 1 - y),
+			_ => (y, width - 1 - x),
+		}
+	}
+}
+impl From<FlipDirection> for Transform {
+	fn from(flip_direction: FlipDirection) -> Self {
+		match flip_direction {
+			// top to bottom is left to right and half a turn
+			FlipDirection::Horizontal => Transform::new(2, true),
+			FlipDirection::Vertical => Transform::new(0, true),
+		}
+	}
+}
+impl From<Rotation> for Transform {
+	fn from(rotation: Rotation) -> Self {
+		match rotation {
+			Rotation::R0 => Transform::new(0, false),
+			Rotation::R90 => Transform::new(1, false),
+			Rotation::R180 => Transform::new(2, false),
+			Rotation::R270 => Transform::new(3, false),
+		}
+	}
+}
+
+// A grid as it would look after a transform, without copying it. Each position is
+// mapped back to the grid it looks at when it is read.
+#[derive(Debug, Clone)]
+pub struct View<'a, T> {
+	grid: &'a Grid<T>,
+	inverse: Transform,
+	pub size: Vec2<usize>,
+}
+impl<T> View<'_, T> {
+	pub fn transform(&self) -> Transform {
+		self.inverse.inverse()
+	}
+	pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+		if x >= self.size.x || y >= self.size.y {
+			return None;
+		}
+		let (x, y) = self.inverse.apply((x, y), (self.size.x, self.size.y));
+		Some(&self.grid.data[self.grid.index(x, y)])
+	}
+}
+impl<T> Index<(usize, usize)> for View<'_, T> {
+	type Output = T;
+	fn index(&self, (x, y): (usize, usize)) -> &T {
+		self.get(x, y).unwrap_or_else(|| {
+			panic!(
+				"({}, {}) is outside a {}x{} view",
+				x, y, self.size.x, self.size.y
+			)
+		})
+	}
+}
+
+// A view of a grid in each of its 8 orientations.
+#[derive(Debug, Clone)]
+pub struct Orientations<'a, T> {
+	grid: &'a Grid<T>,
+	transforms: slice::Iter<'static, Transform>,
+}
+impl<'a, T> Iterator for Orientations<'a, T>
+where
+	T: Clone,
+{
+	type Item = View<'a, T>;
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(self.grid.view(*self.transforms.next()?))
+	}
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.transforms.size_hint()
+	}
+}
+
+// The cells of a pattern read from an ASCII mask, one line for each row. Every byte
+// but the wildcard is a cell the grid has to hold parse_cell's value for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern<T> {
+	pub size: Vec2<usize>,
+	cells: Vec<((usize, usize), T)>,
+}
+impl<T> Pattern<T> {
+	pub fn parse(mask: &str, wildcard: u8, parse_cell: impl Fn(u8) -> T) -> Self {
+		let width = mask.lines().map(str::len).max().unwrap_or(0);
+		let cells = mask
+			.lines()
+			.enumerate()
+			.flat_map(|(y, line)| {
+				line.bytes()
+					.enumerate()
+					.filter(|(_, byte)| *byte != wildcard)
+					.map(move |(x, byte)| ((x, y), byte))
+			})
+			.map(|(cell, byte)| (cell, parse_cell(byte)))
+			.collect();
+		Self {
+			size: Vec2::new(width, mask.lines().count()),
+			cells,
+		}
+	}
+	pub fn cells(&self) -> slice::Iter<'_, ((usize, usize), T)> {
+		self.cells.iter()
+	}
+	pub fn transformed(&self, transform: Transform) -> Self
+	where
+		T: Clone,
+	{
+		let size = (self.size.x, self.size.y);
+		let (x, y) = transform.size(size);
+		Self {
+			size: Vec2::new(x, y),
+			cells: self
+				.cells
+				.iter()
+				.map(|(cell, value)| (transform.apply(*cell, size), value.clone()))
+				.collect(),
+		}
+	}
+	// The cells of the grid covered by a match this pattern's find or find_oriented found.
+	pub fn covered<'a>(&'a self, found: &Match) -> impl Iterator<Item = (usize, usize)> + 'a {
+		let size = (self.size.x, self.size.y);
+		let (transform, x, y) = (found.transform, found.x, found.y);
+		self.cells.iter().map(move |(cell, _)| {
+			let (dx, dy) = transform.apply(*cell, size);
+			(x + dx, y + dy)
+		})
+	}
+	pub fn matches_at(&self, grid: &Grid<T>, x: usize, y: usize) -> bool
+	where
+		T: PartialEq,
+	{
+		self.cells.iter().all(|((dx, dy), value)| {
+			grid.contains(x + dx, y + dy) && grid.data[grid.index(x + dx, y + dy)] == *value
+		})
+	}
+	// Where the pattern is found as it is, top left corners in reading order.
+	pub fn find(&self, grid: &Grid<T>, overlap: Overlap) -> Vec<Match>
+	where
+		T: PartialEq,
+	{
+		let mut covered = Vec::new();
+		self.find_into(grid, Transform::IDENTITY, overlap, &mut covered)
+	}
+	// Where the pattern is found turned and flipped every way, in the order of
+	// Transform::ALL. Matches in different orientations can't overlap either when
+	// overlaps are skipped. A symmetric pattern looks the same in several orientations
+	// so a match covering the same cells as an earlier one is left out.
+	pub fn find_oriented(&self, grid: &Grid<T>, overlap: Overlap) -> Vec<Match>
+	where
+		T: Clone + PartialEq,
+	{
+		let mut covered = Vec::new();
+		let mut seen = HashSet::new();
+		Transform::all()
+			.flat_map(|transform| {
+				self.transformed(transform)
+					.find_into(grid, transform, overlap, &mut covered)
+			})
+			.filter(|found| {
+				let mut cells = self.covered(found).collect::<Vec<_>>();
+				cells.sort_unstable();
+				seen.insert((found.x, found.y, cells))
+			})
+			.collect()
+	}
+	fn find_into(
+		&self,
+		grid: &Grid<T>,
+		transform: Transform,
+		overlap: Overlap,
+		covered: &mut Vec<bool>,
+	) -> Vec<Match>
+	where
+		T: PartialEq,
+	{
+		let mut found = Vec::new();
+		if self.size.x > grid.size.x || self.size.y > grid.size.y {
+			return found;
+		}
+		if overlap == Overlap::Skipped && covered.is_empty() {
+			covered.resize(grid.data.len(), false);
+		}
+		for y in 0..=(grid.size.y - self.size.y) {
+			for x in 0..=(grid.size.x - self.size.x) {
+				if !self.matches_at(grid, x, y) {
+					continue;
+				}
+				if overlap == Overlap::Skipped {
+					let cells = self
+						.cells
+						.iter()
+						.map(|((dx, dy), _)| grid.index(x + dx, y + dy));
+					if cells.clone().any(|i| covered[i]) {
+						continue;
+					}
+					cells.for_each(|i| covered[i] = true);
+				}
+				found.push(Match { x, y, transform });
+			}
+		}
+		found
+	}
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Overlap {
+	Allowed,
+	// A match that shares a cell with an earlier one is left out.
+	Skipped,
+}
+
+// The top left corner of a pattern found in a grid and how the pattern was turned.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Match {
+	pub x: usize,
+	pub y: usize,
+	pub transform: Transform,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::super::memory;
+	use super::*;
+	#[test]
+	fn access_test() {
+		let mut grid = Grid::new(3, 2, &0);
+		grid[(2, 1)] = 5;
+		*grid.get_mut(0, 1).unwrap() = 3;
+		assert_eq!(grid.get(2, 1), Some(&5));
+		assert_eq!(grid[(0, 1)], 3);
+		assert_eq!(grid.get(3, 0), None);
+		assert_eq!(grid.get(0, 2), None);
+		assert_eq!(grid.get_mut(3, 1), None);
+		assert_eq!(grid.get_i(2, 1), Some(&5));
+		assert_eq!(grid.get_i(-1, 0), None);
+		assert_eq!(grid.get_i(0, -1), None);
+		assert_eq!(grid.data, vec![0, 0, 0, 3, 0, 5]);
+	}
+	#[test]
+	fn adjacent_test() {
+		let mut grid = Grid::new(3, 2, &0);
+		grid.data = vec![1, 2, 3, 4, 5, 6];
+		assert_eq!(
+			grid.adjacent(0, 0).collect::<Vec<_>>(),
+			vec![(0, 1), (1, 0)]
+		);
+		assert_eq!(grid.diagonal_adjacent(1, 0).count(), 5);
+		assert_eq!(grid.diagonal_adjacent(1, 1).count(), 5);
+		assert_eq!(
+			grid.adjacent_cells(2, 1).collect::<Vec<_>>(),
+			vec![((1, 1), &5), ((2, 0), &3)]
+		);
+		let knight = [(2, 1), (-2, -1), (1, 2)];
+		assert_eq!(
+			grid.adjacent_by(0, 0, &knight).collect::<Vec<_>>(),
+			vec![(2, 1)]
+		);
+		assert_eq!(
+			grid.diagonal_adjacent_cells(0, 1)
+				.map(|(_, cell)| cell)
+				.sum::<i32>(),
+			1 + 2 + 5
+		);
+		let (sum, allocations) = memory::measure(|| {
+			grid.adjacent_cells_by(0, 0, &knight)
+				.chain(grid.diagonal_adjacent_cells(1, 1))
+				.map(|(_, cell)| cell)
+				.sum::<i32>()
+		});
+		assert_eq!(sum, 6 + 1 + 4 + 2 + 3 + 6);
+		assert_eq!(allocations.map_or(0, |allocations| allocations.total), 0);
+	}
+	#[test]
+	fn transform_test() {
+		// 1 2 3
+		// 4 5 6
+		let mut grid = Grid::new(3, 2, &0);
+		grid.data = vec![1, 2, 3, 4, 5, 6];
+		let turned = grid.transformed(Transform::ROTATE);
+		assert_eq!((turned.size.x, turned.size.y), (2, 3));
+		assert_eq!(turned.data, vec![4, 1, 5, 2, 6, 3]);
+		let mut flipped = grid.clone();
+		flipped.flip(FlipDirection::Horizontal);
+		assert_eq!(flipped.data, vec![4, 5, 6, 1, 2, 3]);
+		flipped.flip(FlipDirection::Vertical);
+		assert_eq!(flipped.data, grid.transformed(Rotation::R180.into()).data);
+
+		let orientations = grid.orientations().collect::<Vec<_>>();
+		assert_eq!(orientations.len(), 8);
+		for (view, a) in orientations.iter().zip(Transform::all()) {
+			let transformed = grid.transformed(a);
+			assert_eq!(view.transform(), a);
+			assert_eq!(view.size, transformed.size);
+			assert_eq!(view[(1, 1)], transformed[(1, 1)]);
+			assert_eq!(view.get(view.size.x, 0), None);
+			assert_eq!(transformed.transformed(a.inverse()), grid);
+			for (x, y) in [(0, 0), (2, 0), (1, 1)].iter() {
+				assert_eq!(transformed[a.apply((*x, *y), (3, 2))], grid[(*x, *y)]);
+			}
+			for b in Transform::all() {
+				assert_eq!(transformed.transformed(b), grid.transformed(a.then(b)));
+			}
+		}
+		let distinct = orientations
+			.iter()
+			.map(|view| (view.size, view[(0, 0)], view[(1, 0)]))
+			.collect::<HashSet<_>>();
+		assert_eq!(distinct.len(), 8);
+		assert_eq!(Rotation::R270.turned(), Rotation::R0);
+	}
+	#[test]
+	fn parse_test() {
+		let parse_cell = |byte| match byte {
+			b'#' => Ok(true),
+			b'.' => Ok(false),
+			_ => Err(()),
+		};
+		let grid = Grid::parse(2020, 1, "#..\n.#.\n", parse_cell).unwrap();
+		assert_eq!((grid.size.x, grid.size.y), (3, 2));
+		assert!(grid[(1, 1)] && !grid[(2, 1)]);
+		let render = grid.render(|cell| if *cell { '#' } else { '.' });
+		assert_eq!(render, "#..\n.#.\n");
+		assert_eq!(Grid::parse(2020, 1, &render, parse_cell), Ok(grid.clone()));
+		assert_eq!(
+			grid.transformed(Transform::ROTATE).to_string(),
+			"false true\ntrue false\nfalse false\n"
+		);
+
+		let error = Grid::parse(2020, 1, "#..\n.x.\n", parse_cell).unwrap_err();
+		assert_eq!((error.day, error.line, error.column), (1, 2, 2));
+		assert_eq!(error.message, "unexpected 'x'");
+		let error = Grid::parse(2020, 1, "#..\n.#\n", parse_cell).unwrap_err();
+		assert_eq!((error.line, error.column), (2, 1));
+		assert_eq!(error.message, "expected 3 bytes but found 2");
+		let problems = Grid::problems(2020, 1, "#.\n.é\n#x.", parse_cell);
+		let positions = problems
+			.iter()
+			.map(|problem| (problem.line, problem.column))
+			.collect::<Vec<_>>();
+		assert_eq!(positions, [(2, 1), (2, 2), (3, 1), (3, 2)]);
+		assert!(Grid::problems(2020, 1, &render, parse_cell).is_empty());
+		assert_eq!(
+			Grid::parse(2020, 1, "", parse_cell).unwrap().data,
+			Vec::new()
+		);
+	}
+	#[test]
+	fn pattern_test() {
+		let parse_cell = |byte| match byte {
+			b'#' => Ok(true),
+			b'.' => Ok(false),
+			_ => Err(()),
+		};
+		let grid = Grid::parse(2020, 1, "#..\n##.\n###\n", parse_cell).unwrap();
+		// an L with a wildcard in the corner
+		let pattern = Pattern::parse("#?\n##", b'?', |byte| byte == b'#');
+		assert_eq!((pattern.size.x, pattern.size.y), (2, 2));
+		assert_eq!(pattern.cells().count(), 3);
+
+		let corners = |found: &[Match]| found.iter().map(|m| (m.x, m.y)).collect::<Vec<_>>();
+		let found = pattern.find(&grid, Overlap::Allowed);
+		assert_eq!(corners(&found), [(0, 0), (0, 1), (1, 1)]);
+		let found = pattern.find(&grid, Overlap::Skipped);
+		assert_eq!(corners(&found), [(0, 0)]);
+		assert_eq!(
+			pattern.covered(&found[0]).collect::<Vec<_>>(),
+			[(0, 0), (0, 1), (1, 1)]
+		);
+
+		// the L looks the same flipped over its other diagonal
+		let cells = |m: &Match| {
+			let mut cells = pattern.covered(m).collect::<Vec<_>>();
+			cells.sort_unstable();
+			cells
+		};
+		let found = pattern.find_oriented(&grid, Overlap::Allowed);
+		let turned = Transform::all()
+			.flat_map(|transform| {
+				let found = pattern.transformed(transform).find(&grid, Overlap::Allowed);
+				found.into_iter().map(move |m| Match { transform, ..m })
+			})
+			.map(|m| cells(&m))
+			.collect::<HashSet<_>>();
+		assert_eq!(found.iter().map(cells).collect::<HashSet<_>>(), turned);
+		assert_eq!(found.len(), turned.len());
+		assert!(found.len() > 3);
+		for m in found.iter() {
+			assert!(pattern.covered(m).all(|(x, y)| grid[(x, y)]));
+		}
+		let found = pattern.find_oriented(&grid, Overlap::Skipped);
+		let covered = found
+			.iter()
+			.flat_map(|m| pattern.covered(m))
+			.collect::<HashSet<_>>();
+		assert_eq!(covered.len(), 3 * found.len());
+		assert_eq!(found.len(), 1);
+
+		let wide = Pattern::parse("####", b'?', |byte| byte == b'#');
+		assert!(wide.find_oriented(&grid, Overlap::Allowed).is_empty());
+
+		// a plus looks the same in every orientation so each one is only found once
+		let grid = Grid::parse(2020, 1, ".#...\n###..\n.#.#.\n..###\n...#.\n", parse_cell).unwrap();
+		let plus = Pattern::parse("?#?\n###\n?#?", b'?', |byte| byte == b'#');
+		let found = plus.find_oriented(&grid, Overlap::Allowed);
+		assert_eq!(corners(&found), [(0, 0), (2, 2)]);
+		assert!(found.iter().all(|m| m.transform == Transform::IDENTITY));
+	}
+	#[test]
+	#[should_panic]
+	fn index_test() {
+		let grid = Grid::new(3, 2, &0);
+		let _ = grid[(0, 2)];
+	}
+}
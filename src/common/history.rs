@@ -0,0 +1,266 @@
+// File: common/history.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use super::bench::{Phase, PhaseStats, Stats};
+use super::report::milliseconds;
+
+const HEADER: &str = "run,year,day,phase,min_ns,median_ns,mean_ns,std_dev_ns,p95_ns";
+
+// A csv file with one row per day and phase of every saved benchmark run.
+// Runs are identified by the unix time they were saved at.
+pub fn append(path: &Path, run: u64, stats: &[PhaseStats]) -> io::Result<()> {
+	let is_new = !path.exists();
+	let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+	let mut out = String::new();
+	if is_new {
+		out.push_str(HEADER);
+		out.push('\n');
+	}
+	for phase in stats.iter() {
+		out.push_str(&format!(
+			"{},{},{},{},{},{},{},{},{}\n",
+			run,
+			phase.year,
+			phase.day,
+			phase.phase.name(),
+			phase.stats.min.as_nanos(),
+			phase.stats.median.as_nanos(),
+			phase.stats.mean.as_nanos(),
+			phase.stats.std_dev.as_nanos(),
+			phase.stats.p95.as_nanos()
+		));
+	}
+	file.write_all(out.as_bytes())
+}
+
+pub fn load(path: &Path) -> io::Result<Vec<(u64, PhaseStats)>> {
+	let contents = fs::read_to_string(path)
+		.map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+	contents
+		.lines()
+		.enumerate()
+		.filter(|(_, line)| *line != HEADER && !line.is_empty())
+		.map(|(i, line)| {
+			parse_row(line).ok_or_else(|| {
+				io::Error::new(
+					io::ErrorKind::InvalidData,
+					format!(
+						"{}: line {}: malformed row \"{}\"",
+						path.display(),
+						i + 1,
+						line
+					),
+				)
+			})
+		})
+		.collect()
+}
+fn parse_row(line: &str) -> Option<(u64, PhaseStats)> {
+	let columns = line.split(',').collect::<Vec<_>>();
+	if columns.len() != 9 {
+		return None;
+	}
+	let nanos = |column: &str| column.parse::<u64>().ok().map(Duration::from_nanos);
+	Some((
+		columns[0].parse().ok()?,
+		PhaseStats {
+			year: columns[1].parse().ok()?,
+			day: columns[2].parse().ok()?,
+			phase: Phase::from_name(columns[3])?,
+			stats: Stats {
+				min: nanos(columns[4])?,
+				median: nanos(columns[5])?,
+				mean: nanos(columns[6])?,
+				std_dev: nanos(columns[7])?,
+				p95: nanos(columns[8])?,
+			},
+		},
+	))
+}
+
+// The most recently saved stats of every day and phase.
+pub fn baseline(history: &[(u64, PhaseStats)]) -> HashMap<(u16, u8, Phase), (u64, Stats)> {
+	let mut baseline = HashMap::new();
+	for (run, phase) in history.iter() {
+		let latest = baseline
+			.entry((phase.year, phase.day, phase.phase))
+			.or_insert((*run, phase.stats));
+		if *run >= latest.0 {
+			*latest = (*run, phase.stats);
+		}
+	}
+	baseline
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+	pub year: u16,
+	pub day: u8,
+	pub phase: Phase,
+	pub baseline: Option<Duration>,
+	pub current: Duration,
+}
+impl Comparison {
+	// Percent change of the median, positive is slower. None without a baseline or
+	// when it is zero, as there is no percentage of it to take.
+	pub fn change(&self) -> Option<f64> {
+		self.baseline
+			.filter(|baseline| !baseline.is_zero())
+			.map(|baseline| {
+				(self.current.as_secs_f64() - baseline.as_secs_f64()) / baseline.as_secs_f64()
+					* 100.0
+			})
+	}
+	pub fn regressed(&self, threshold: f64) -> bool {
+		self.change().is_some_and(|change| change > threshold)
+	}
+}
+
+pub fn compare(
+	baseline: &HashMap<(u16, u8, Phase), (u64, Stats)>,
+	current: &[PhaseStats],
+) -> Vec<Comparison> {
+	current
+		.iter()
+		.map(|phase| Comparison {
+			year: phase.year,
+			day: phase.day,
+			phase: phase.phase,
+			baseline: baseline
+				.get(&(phase.year, phase.day, phase.phase))
+				.map(|(_, stats)| stats.median),
+			current: phase.stats.median,
+		})
+		.collect()
+}
+
+pub fn table_header() -> String {
+	format!(
+		"{:<5} {:<4} {:<7} {:>12} {:>12} {:>9}\n",
+		"year", "day", "phase", "baseline", "median", "change"
+	)
+}
+pub fn table_rows(comparisons: &[Comparison], threshold: f64) -> String {
+	comparisons
+		.iter()
+		.map(|comparison| {
+			let baseline = comparison
+				.baseline
+				.map_or_else(|| String::from("none"), milliseconds);
+			let change = comparison
+				.change()
+				.map_or_else(String::new, |change| format!("{:+.1}%", change));
+			format!(
+				"{:<5} {:<4} {:<7} {:>12} {:>12} {:>9}{}\n",
+				comparison.year,
+				comparison.day,
+				comparison.phase.name(),
+				baseline,
+				milliseconds(comparison.current),
+				change,
+				if comparison.regressed(threshold) {
+					" REGRESSED"
+				} else {
+					""
+				}
+			)
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::common::registry::Part;
+	fn stats(day: u8, phase: Phase, median: u64) -> PhaseStats {
+		let median = Duration::from_nanos(median);
+		PhaseStats {
+			year: 2020,
+			day,
+			phase,
+			stats: Stats {
+				min: median,
+				median,
+				mean: median,
+				std_dev: Duration::default(),
+				p95: median,
+			},
+		}
+	}
+	#[test]
+	fn compare_test() {
+		let row = "7,2020,3,part 2,100,200,300,40,500";
+		assert_eq!(
+			parse_row(row),
+			Some((
+				7,
+				PhaseStats {
+					year: 2020,
+					day: 3,
+					phase: Phase::Solve(Part::Two),
+					stats: Stats {
+						min: Duration::from_nanos(100),
+						median: Duration::from_nanos(200),
+						mean: Duration::from_nanos(300),
+						std_dev: Duration::from_nanos(40),
+						p95: Duration::from_nanos(500),
+					},
+				}
+			))
+		);
+		assert_eq!(parse_row("7,2020,3,part 3,100,200,300,40,500"), None);
+
+		let history = vec![
+			(1, stats(1, Phase::Parse, 1000)),
+			(2, stats(1, Phase::Parse, 100)),
+			(1, stats(2, Phase::Parse, 100)),
+			(1, stats(4, Phase::Parse, 0)),
+		];
+		let current = vec![
+			stats(1, Phase::Parse, 111),
+			stats(2, Phase::Parse, 105),
+			stats(3, Phase::Parse, 100),
+			stats(4, Phase::Parse, 100),
+		];
+		let comparisons = compare(&baseline(&history), &current);
+		let regressed = comparisons
+			.iter()
+			.map(|comparison| comparison.regressed(10.0))
+			.collect::<Vec<_>>();
+		assert_eq!(regressed, vec![true, false, false, false]);
+		assert_eq!(comparisons[2].baseline, None);
+		assert_eq!(comparisons[3].change(), None);
+		assert_eq!(
+			table_rows(&comparisons[3..], 10.0),
+			"2020  4    parse        0.000ms      0.000ms          \n"
+		);
+	}
+}
@@ -0,0 +1,359 @@
+// File: common/line_editor.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+use std::iter::Peekable;
+use std::process::{Command, Stdio};
+
+// Finds the candidates for the word before the cursor, returning where that word starts.
+pub type Completer<'a> = &'a dyn Fn(&str) -> (usize, Vec<String>);
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Key {
+	Char(char),
+	Backspace,
+	Delete,
+	Left,
+	Right,
+	Home,
+	End,
+	Up,
+	Down,
+	Tab,
+	Enter,
+	// ctrl-c
+	Interrupt,
+	// ctrl-d
+	Eof,
+	Other,
+}
+
+// Reads a line with history and tab completion when stdin is a terminal. The terminal is
+// put in raw mode with stty while the line is edited, where that fails or stdin is a pipe
+// the line is read as it is. None is the end of the input.
+pub fn read_line(
+	prompt: &str,
+	history: &[String],
+	complete: Completer,
+) -> io::Result<Option<String>> {
+	let mut stdout = io::stdout();
+	write!(stdout, "{}", prompt)?;
+	stdout.flush()?;
+	let saved = match io::stdin().is_terminal() {
+		true => stty(&["-g"]).ok(),
+		false => None,
+	};
+	let saved = saved.filter(|_| stty(&["-icanon", "-echo", "-isig", "min", "1"]).is_ok());
+	let saved = match saved {
+		Some(saved) => saved,
+		None => {
+			let mut line = String::new();
+			return match io::stdin().lock().read_line(&mut line)? {
+				0 => Ok(None),
+				_ => Ok(Some(line.trim_end_matches(&['\r', '\n'][..]).to_string())),
+			};
+		}
+	};
+	let line = edit(prompt, history, complete);
+	stty(&[saved.trim()])?;
+	line
+}
+
+fn stty(arguments: &[&str]) -> io::Result<String> {
+	let output = Command::new("stty")
+		.args(arguments)
+		.stdin(Stdio::inherit())
+		.output()?;
+	match output.status.success() {
+		true => Ok(String::from_utf8_lossy(&output.stdout).into_owned()),
+		false => Err(io::Error::other("stty failed")),
+	}
+}
+
+fn edit(prompt: &str, history: &[String], complete: Completer) -> io::Result<Option<String>> {
+	let stdin = io::stdin();
+	let mut bytes = stdin.lock().bytes().peekable();
+	let mut stdout = io::stdout();
+	let mut line = Line::new(history);
+	loop {
+		let key = match read_key(&mut bytes)? {
+			Some(key) => key,
+			None => return Ok(None),
+		};
+		match line.apply(key, complete) {
+			Step::Edited => (),
+			Step::List(candidates) => write!(stdout, "\r\n{}\r\n", candidates.join("  "))?,
+			Step::Submit(text) => {
+				write!(stdout, "\r\n")?;
+				stdout.flush()?;
+				return Ok(Some(text));
+			}
+			Step::Cancel => write!(stdout, "^C\r\n")?,
+			Step::Eof => {
+				write!(stdout, "\r\n")?;
+				stdout.flush()?;
+				return Ok(None);
+			}
+		}
+		let after_cursor = line.text.len() - line.cursor;
+		write!(
+			stdout,
+			"\r{}{}\x1b[K",
+			prompt,
+			line.text.iter().collect::<String>()
+		)?;
+		if after_cursor > 0 {
+			write!(stdout, "\x1b[{}D", after_cursor)?;
+		}
+		stdout.flush()?;
+	}
+}
+
+// Decodes a key press, including the escape sequences of the arrow keys and utf-8.
+// None is the end of the input.
+pub fn read_key(
+	bytes: &mut Peekable<impl Iterator<Item = io::Result<u8>>>,
+) -> io::Result<Option<Key>> {
+	let byte = match bytes.next() {
+		Some(byte) => byte?,
+		None => return Ok(None),
+	};
+	let key = match byte {
+		b'\r' | b'\n' => Key::Enter,
+		b'\t' => Key::Tab,
+		0x7f | 0x08 => Key::Backspace,
+		0x01 => Key::Home,
+		0x05 => Key::End,
+		0x03 => Key::Interrupt,
+		0x04 => Key::Eof,
+		// a lone escape is a key of its own, the byte after it starts the next key
+		0x1b if !matches!(bytes.peek(), Some(Ok(b'[' | b'O'))) => Key::Other,
+		0x1b => {
+			let mut sequence = Vec::new();
+			// "[" or "O" followed by parameters and a final letter or "~"
+			for byte in bytes.by_ref() {
+				let byte = byte?;
+				sequence.push(byte);
+				if sequence.len() > 1 && (byte.is_ascii_alphabetic() || byte == b'~') {
+					break;
+				}
+			}
+			match &sequence[..] {
+				b"[A" | b"OA" => Key::Up,
+				b"[B" | b"OB" => Key::Down,
+				b"[C" | b"OC" => Key::Right,
+				b"[D" | b"OD" => Key::Left,
+				b"[H" | b"OH" | b"[1~" | b"[7~" => Key::Home,
+				b"[F" | b"OF" | b"[4~" | b"[8~" => Key::End,
+				b"[3~" => Key::Delete,
+				_ => Key::Other,
+			}
+		}
+		0x00..=0x1f => Key::Other,
+		_ => {
+			let len = match byte {
+				0xf0..=0xff => 4,
+				0xe0..=0xef => 3,
+				0xc0..=0xdf => 2,
+				_ => 1,
+			};
+			let mut encoded = vec![byte];
+			for _ in 1..len {
+				match bytes.next() {
+					Some(byte) => encoded.push(byte?),
+					None => break,
+				}
+			}
+			match std::str::from_utf8(&encoded)
+				.ok()
+				.and_then(|s| s.chars().next())
+			{
+				Some(c) => Key::Char(c),
+				None => Key::Other,
+			}
+		}
+	};
+	Ok(Some(key))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Step {
+	Edited,
+	// more than one completion, printed below the line
+	List(Vec<String>),
+	Submit(String),
+	Cancel,
+	Eof,
+}
+
+// The line being edited. Browsing the history keeps what was typed so far to come back to.
+struct Line<'a> {
+	text: Vec<char>,
+	cursor: usize,
+	history: &'a [String],
+	history_index: usize,
+	draft: Vec<char>,
+}
+impl<'a> Line<'a> {
+	fn new(history: &'a [String]) -> Self {
+		Self {
+			text: Vec::new(),
+			cursor: 0,
+			history,
+			history_index: history.len(),
+			draft: Vec::new(),
+		}
+	}
+	fn apply(&mut self, key: Key, complete: Completer) -> Step {
+		match key {
+			Key::Char(c) => {
+				self.text.insert(self.cursor, c);
+				self.cursor += 1;
+			}
+			Key::Backspace if self.cursor > 0 => {
+				self.cursor -= 1;
+				self.text.remove(self.cursor);
+			}
+			Key::Delete if self.cursor < self.text.len() => {
+				self.text.remove(self.cursor);
+			}
+			Key::Left => self.cursor = self.cursor.saturating_sub(1),
+			Key::Right => self.cursor = (self.cursor + 1).min(self.text.len()),
+			Key::Home => self.cursor = 0,
+			Key::End => self.cursor = self.text.len(),
+			Key::Up if self.history_index > 0 => {
+				if self.history_index == self.history.len() {
+					self.draft = self.text.clone();
+				}
+				self.history_index -= 1;
+				self.text = self.history[self.history_index].chars().collect();
+				self.cursor = self.text.len();
+			}
+			Key::Down if self.history_index < self.history.len() => {
+				self.history_index += 1;
+				self.text = match self.history.get(self.history_index) {
+					Some(line) => line.chars().collect(),
+					None => self.draft.clone(),
+				};
+				self.cursor = self.text.len();
+			}
+			Key::Tab => return self.complete(complete),
+			Key::Enter => return Step::Submit(self.text.iter().collect()),
+			Key::Interrupt => {
+				*self = Self::new(self.history);
+				return Step::Cancel;
+			}
+			Key::Eof if self.text.is_empty() => return Step::Eof,
+			_ => (),
+		}
+		Step::Edited
+	}
+	// Fills in as much as every candidate has in common and lists them when there is more
+	// than one.
+	fn complete(&mut self, complete: Completer) -> Step {
+		let before = self.text[..self.cursor].iter().collect::<String>();
+		let (start, candidates) = complete(&before);
+		let common = match candidates.split_first() {
+			Some((first, rest)) => rest.iter().fold(first.as_str(), |common, candidate| {
+				let len = common
+					.char_indices()
+					.zip(candidate.chars())
+					.find(|((_, a), b)| a != b)
+					.map_or(common.len().min(candidate.len()), |((i, _), _)| i);
+				&common[..len]
+			}),
+			None => return Step::Edited,
+		};
+		let typed = before[start..].chars().count();
+		let mut added = common.chars().skip(typed).collect::<Vec<_>>();
+		if candidates.len() == 1 {
+			added.push(' ');
+		}
+		let added_len = added.len();
+		self.text.splice(self.cursor..self.cursor, added);
+		self.cursor += added_len;
+		match candidates.len() {
+			1 => Step::Edited,
+			_ if added_len > 0 => Step::Edited,
+			_ => Step::List(candidates),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	#[test]
+	fn line_test() {
+		let history = vec![String::from("day 15"), String::from("run")];
+		let complete = |line: &str| {
+			let start = line.rfind(' ').map_or(0, |i| i + 1);
+			let candidates = ["day", "days", "query"]
+				.iter()
+				.filter(|name| name.starts_with(&line[start..]))
+				.map(|name| name.to_string())
+				.collect();
+			(start, candidates)
+		};
+		let mut bytes = b"qu\tx\x1b[D\x1b[3~\x1b[A\x1b[A\x1b[B\x1b[B\x1b[Hd\t\t"
+			.iter()
+			.map(|byte| Ok(*byte))
+			.peekable();
+		let mut line = Line::new(&history);
+		let mut steps = Vec::new();
+		while let Some(key) = read_key(&mut bytes).unwrap() {
+			steps.push(line.apply(key, &complete));
+		}
+		assert_eq!(line.text.iter().collect::<String>(), "dayquery ");
+		assert_eq!(
+			steps.last(),
+			Some(&Step::List(vec![String::from("day"), String::from("days")]))
+		);
+		assert_eq!(
+			line.apply(Key::Enter, &complete),
+			Step::Submit(String::from("dayquery "))
+		);
+	}
+	#[test]
+	fn escape_test() {
+		let mut bytes = "a\x1bb\x1b\x1b[Cé\x1b".bytes().map(Ok).peekable();
+		let mut keys = Vec::new();
+		while let Some(key) = read_key(&mut bytes).unwrap() {
+			keys.push(key);
+		}
+		assert_eq!(
+			keys,
+			[
+				Key::Char('a'),
+				Key::Other,
+				Key::Char('b'),
+				Key::Other,
+				Key::Right,
+				Key::Char('é'),
+				Key::Other
+			]
+		);
+	}
+}
@@ -0,0 +1,50 @@
+// File: common/lint.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use super::parse_error::ParseError;
+use super::ChallengeT;
+
+// Every problem found parsing the items of an input, like its lines or groups, each on
+// their own so one bad item doesn't hide the next. Only once every item is fine is the
+// input parsed as a whole for the problems that span items. Problems are in the order
+// they appear in the input.
+pub fn each<C, T>(
+	input: &str,
+	items: impl IntoIterator<Item = Result<T, ParseError>>,
+) -> Vec<ParseError>
+where
+	C: ChallengeT,
+{
+	let mut problems = items
+		.into_iter()
+		.filter_map(Result::err)
+		.collect::<Vec<_>>();
+	problems.sort_by_key(|problem| (problem.line, problem.column));
+	if problems.is_empty() {
+		C::from_input(input).err().into_iter().collect()
+	} else {
+		problems
+	}
+}
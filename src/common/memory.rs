@@ -0,0 +1,137 @@
+// File: common/memory.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Allocations {
+	// The most bytes alive at once on top of those alive when the phase started.
+	pub peak: u64,
+	// Every byte allocated during the phase, even if it was freed again.
+	pub total: u64,
+}
+
+// Counted per thread so the threaded runner can measure each day on its own.
+// Memory freed on another thread than it was allocated on makes current drift,
+// which is why it is signed.
+struct Counts {
+	current: Cell<i64>,
+	peak: Cell<i64>,
+	total: Cell<u64>,
+}
+thread_local! {
+	static COUNTS: Counts = const {
+		Counts {
+			current: Cell::new(0),
+			peak: Cell::new(0),
+			total: Cell::new(0),
+		}
+	};
+}
+
+fn allocated(size: usize) {
+	let _ = COUNTS.try_with(|counts| {
+		let current = counts.current.get() + size as i64;
+		counts.current.set(current);
+		counts.peak.set(counts.peak.get().max(current));
+		counts.total.set(counts.total.get() + size as u64);
+	});
+}
+fn freed(size: usize) {
+	let _ = COUNTS.try_with(|counts| counts.current.set(counts.current.get() - size as i64));
+}
+
+// Passes everything on to the system allocator and counts the bytes on the way.
+// A realloc counts as freeing the old block and allocating the new one.
+pub struct CountingAllocator;
+unsafe impl GlobalAlloc for CountingAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		let ptr = unsafe { System.alloc(layout) };
+		if !ptr.is_null() {
+			allocated(layout.size());
+		}
+		ptr
+	}
+	unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+		let ptr = unsafe { System.alloc_zeroed(layout) };
+		if !ptr.is_null() {
+			allocated(layout.size());
+		}
+		ptr
+	}
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		unsafe { System.dealloc(ptr, layout) };
+		freed(layout.size());
+	}
+	unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+		let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+		if !new_ptr.is_null() {
+			freed(layout.size());
+			allocated(new_size);
+		}
+		new_ptr
+	}
+}
+
+// Counts what f allocates on this thread. Nothing is counted unless the
+// "count-allocations" feature makes CountingAllocator the global allocator.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+	if !cfg!(feature = "count-allocations") {
+		return (f(), None);
+	}
+	let (current, total) = COUNTS.with(|counts| {
+		counts.peak.set(counts.current.get());
+		(counts.current.get(), counts.total.get())
+	});
+	let value = f();
+	let allocations = COUNTS.with(|counts| Allocations {
+		peak: (counts.peak.get() - current).max(0) as u64,
+		total: counts.total.get() - total,
+	});
+	(value, Some(allocations))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	#[test]
+	fn measure_test() {
+		let (len, allocations) = measure(|| {
+			let small = vec![0u8; 1000];
+			drop(small);
+			vec![0u8; 4000].len()
+		});
+		assert_eq!(len, 4000);
+		assert_eq!(allocations.is_some(), cfg!(feature = "count-allocations"));
+		if let Some(allocations) = allocations {
+			assert!((4000..5000).contains(&allocations.peak));
+			assert!((5000..6000).contains(&allocations.total));
+		}
+	}
+}
@@ -23,39 +23,114 @@ IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
 
+pub mod answers;
+pub mod bench;
 pub mod chinese_remainder_theorem;
+#[cfg(feature = "reference")]
+pub mod differential;
+pub mod example;
+pub mod generator;
 pub mod grid;
+pub mod history;
+pub mod line_editor;
+pub mod lint;
+pub mod memory;
+pub mod parse_error;
+pub mod query;
+pub mod registry;
+pub mod repl;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
 pub mod vec2;
 pub mod vec3;
 pub mod vec4;
 
-use std::fmt;
+use std::fs;
+
+use answers::Answer;
+use example::Example;
+use generator::Rng;
+use parse_error::ParseError;
+use query::Query;
+use registry::Part;
 
 pub trait ChallengeT
 where
 	Self: Sized,
 {
-	type Output1: fmt::Display;
-	type Output2: fmt::Display;
+	// Examples from the puzzle text, checked by the "examples" command and each day's tests.
+	const EXAMPLES: &'static [Example] = &[];
+	// Functions the "repl" command can call with arguments.
+	const QUERIES: &'static [Query] = &[];
+	// Parts the tests solve on every generated input, leaving out ones too slow for it.
+	const GENERATED_PARTS: &'static [Part] = &Part::BOTH;
+	// Parts the tests compare with the reference solutions on the examples and every
+	// generated input.
+	#[cfg(feature = "reference")]
+	const REFERENCE_PARTS: &'static [Part] = &Part::BOTH;
 
 	fn print_result() {
-		println!("{}", Self::result_string());
+		match Self::result_string() {
+			Ok(result) => println!("{}", result),
+			Err(e) => println!("Day {}\n  error: {}", Self::day(), e),
+		}
+	}
+	fn result_string() -> Result<String, ParseError> {
+		Self::result_string_from_input(&Self::read_input()?)
 	}
-	fn result_string() -> String {
-		let challenge = Self::new();
-		format!(
+	fn result_string_from_input(input: &str) -> Result<String, ParseError> {
+		let challenge = Self::from_input(input)?;
+		Ok(format!(
 			"Day {}\n  part 1: {}\n  part 2: {}",
 			Self::day(),
 			challenge.part_1(),
 			challenge.part_2()
-		)
+		))
 	}
 
+	fn year() -> u16;
 	fn day() -> u8;
 
-	fn new() -> Self;
-	fn part_1(&self) -> Self::Output1;
-	fn part_2(&self) -> Self::Output2;
+	fn input_path() -> String {
+		format!("inputs/{}/day_{}.txt", Self::year(), Self::day())
+	}
+	fn read_input() -> Result<String, ParseError> {
+		let path = Self::input_path();
+		fs::read_to_string(&path).map_err(|e| {
+			ParseError::new(
+				Self::year(),
+				Self::day(),
+				0,
+				0,
+				format!("could not read {}: {}", path, e),
+			)
+		})
+	}
+
+	fn new() -> Result<Self, ParseError> {
+		Self::from_input(&Self::read_input()?)
+	}
+	// Only parses the input, the work for each part belongs in part_1 and part_2
+	// so the runner can time every phase on its own and lint never solves a day.
+	fn from_input(input: &str) -> Result<Self, ParseError>;
+	// Every problem with the input instead of just the first one from_input stops at,
+	// for the "lint" command. Days with many items check each of them with lint::each.
+	fn lint(input: &str) -> Vec<ParseError> {
+		Self::from_input(input).err().into_iter().collect()
+	}
+	fn part_1(&self) -> Answer;
+	fn part_2(&self) -> Answer;
+	// A random input with roughly size items like lines or groups. Parts are expected to
+	// have an answer unless the day's generator says otherwise, like days 1, 8 and 21
+	// sometimes leaving one out. The same seed always gives the same input.
+	fn generate(rng: &mut Rng, size: usize) -> String;
+	// Brute force solutions that favour being obviously correct over being fast,
+	// the "differential" command checks the fast parts against them.
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer;
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer;
 }
 
 pub trait NeighborsT
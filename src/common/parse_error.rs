@@ -0,0 +1,126 @@
+// File: common/parse_error.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+// Line and column are 1 based, 0 means the position is unknown.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+	pub year: u16,
+	pub day: u8,
+	pub line: usize,
+	pub column: usize,
+	pub message: String,
+}
+impl ParseError {
+	pub fn new(year: u16, day: u8, line: usize, column: usize, message: impl Into<String>) -> Self {
+		Self {
+			year,
+			day,
+			line,
+			column,
+			message: message.into(),
+		}
+	}
+	// token must be a slice of input so its position can be found from the pointer offset.
+	pub fn at(year: u16, day: u8, input: &str, token: &str, message: impl Into<String>) -> Self {
+		let offset = (token.as_ptr() as usize)
+			.checked_sub(input.as_ptr() as usize)
+			.filter(|offset| *offset <= input.len());
+		match offset {
+			Some(offset) => {
+				let before = &input[..offset];
+				let line = before.matches('\n').count() + 1;
+				let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+				let column = before[line_start..].chars().count() + 1;
+				Self::new(year, day, line, column, message)
+			}
+			None => Self::new(year, day, 0, 0, message),
+		}
+	}
+	// Moves the position of an error found parsing text on its own to where text is in input.
+	pub fn within(self, input: &str, text: &str) -> Self {
+		if self.line == 0 {
+			return self;
+		}
+		let start = Self::at(self.year, self.day, input, text, "");
+		let column = match self.line {
+			1 => start.column + self.column - 1,
+			_ => self.column,
+		};
+		Self::new(
+			self.year,
+			self.day,
+			start.line + self.line - 1,
+			column,
+			self.message,
+		)
+	}
+}
+impl fmt::Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.line {
+			0 => write!(f, "{}/{}: {}", self.year, self.day, self.message),
+			_ => write!(
+				f,
+				"{}/{} line {}, column {}: {}",
+				self.year, self.day, self.line, self.column, self.message
+			),
+		}
+	}
+}
+impl Error for ParseError {}
+
+pub fn parse_number<T>(year: u16, day: u8, input: &str, token: &str) -> Result<T, ParseError>
+where
+	T: FromStr,
+	T::Err: fmt::Display,
+{
+	token.parse().map_err(|e| {
+		ParseError::at(
+			year,
+			day,
+			input,
+			token,
+			format!("invalid number \"{}\": {}", token, e),
+		)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn display_test() {
+		let input = "1\n2\nx3";
+		let error = ParseError::at(2020, 9, input, &input[5..], "unexpected 'x'");
+		assert_eq!(error.to_string(), "2020/9 line 3, column 2: unexpected 'x'");
+		let error = ParseError::new(2020, 9, 0, 0, "no numbers");
+		assert_eq!(error.to_string(), "2020/9: no numbers");
+	}
+}
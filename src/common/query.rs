@@ -1,4 +1,4 @@
-// File: day_25.rs
+// File: common/query.rs
 // Author: Jacob Guenther
 // Date: December 2020
 
@@ -23,63 +23,31 @@ IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
 
-use super::common::*;
-
-pub struct Challenge {
-	part_1_result: usize,
+use super::answers::Answer;
+use super::parse_error::ParseError;
+
+// A function of a day that the "repl" command calls with arguments, for trying out
+// variants of the puzzle like other starting numbers or a single expression.
+#[derive(Debug, Copy, Clone)]
+pub struct Query {
+	pub name: &'static str,
+	// How the arguments are written, like "<starting numbers> <turns>".
+	pub usage: &'static str,
+	pub description: &'static str,
+	pub run: fn(&[&str]) -> Result<Answer, ParseError>,
 }
-impl ChallengeT for Challenge {
-	type Output1 = usize;
-	type Output2 = usize;
-
-	fn day() -> u8 {
-		25
-	}
-	fn new() -> Self {
-		let [public_key_1, public_key_2] = [13233401, 6552760];
-
-		let mut loop_size = 0;
-		let mut result = 1;
-
-		// faster to find secret key 2 first
-		while result != public_key_2 {
-			loop_size += 1;
-			result = (result * 7) % 20201227;
-		}
-
-		let mut result = 1;
-		for _ in 0..loop_size {
-			result = (result * public_key_1) % 20201227;
-		}
-
+impl Query {
+	pub const fn new(
+		name: &'static str,
+		usage: &'static str,
+		description: &'static str,
+		run: fn(&[&str]) -> Result<Answer, ParseError>,
+	) -> Self {
 		Self {
-			part_1_result: result,
+			name,
+			usage,
+			description,
+			run,
 		}
 	}
-	fn part_1(&self) -> Self::Output1 {
-		self.part_1_result
-	}
-	fn part_2(&self) -> Self::Output2 {
-		0
-	}
-}
-
-#[cfg(test)]
-mod tests {
-	use super::Challenge;
-	use crate::common::ChallengeT;
-	use test::Bencher;
-
-	#[test]
-	fn part_1_test() {
-		assert_eq!(Challenge::new().part_1(), 17673381);
-	}
-
-	#[bench]
-	fn both(b: &mut Bencher) {
-		b.iter(|| {
-			let challenge = Challenge::new();
-			challenge.part_1();
-		})
-	}
 }
@@ -0,0 +1,341 @@
+// File: common/registry.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use std::any::Any;
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+
+use super::answers::Answer;
+use super::example::Example;
+use super::generator::Rng;
+use super::memory;
+use super::parse_error::ParseError;
+use super::query::Query;
+use super::report::Record;
+use super::ChallengeT;
+
+pub type Parsed = Box<dyn Any + Send>;
+
+// A type erased day so the runners can be driven from a table instead of naming every day.
+pub struct Entry {
+	pub year: u16,
+	pub day: u8,
+	pub title: &'static str,
+	pub parse: fn(&str) -> Result<Parsed, ParseError>,
+	pub lint: fn(&str) -> Vec<ParseError>,
+	pub part_1: fn(&dyn Any) -> Answer,
+	pub part_2: fn(&dyn Any) -> Answer,
+	#[cfg(feature = "reference")]
+	pub reference_part_1: fn(&dyn Any) -> Answer,
+	#[cfg(feature = "reference")]
+	pub reference_part_2: fn(&dyn Any) -> Answer,
+	pub generate: fn(&mut Rng, usize) -> String,
+	pub examples: &'static [Example],
+	pub queries: &'static [Query],
+	pub generated_parts: &'static [Part],
+	#[cfg(feature = "reference")]
+	pub reference_parts: &'static [Part],
+}
+impl Entry {
+	pub const fn new<C>(year: u16, day: u8, title: &'static str) -> Self
+	where
+		C: ChallengeT + Send + 'static,
+	{
+		Self {
+			year,
+			day,
+			title,
+			parse: parse::<C>,
+			lint: C::lint,
+			part_1: part_1::<C>,
+			part_2: part_2::<C>,
+			#[cfg(feature = "reference")]
+			reference_part_1: reference_part_1::<C>,
+			#[cfg(feature = "reference")]
+			reference_part_2: reference_part_2::<C>,
+			generate: C::generate,
+			examples: C::EXAMPLES,
+			queries: C::QUERIES,
+			generated_parts: C::GENERATED_PARTS,
+			#[cfg(feature = "reference")]
+			reference_parts: C::REFERENCE_PARTS,
+		}
+	}
+	pub fn solve(&self, parsed: &dyn Any, part: Part) -> Answer {
+		match part {
+			Part::One => (self.part_1)(parsed),
+			Part::Two => (self.part_2)(parsed),
+		}
+	}
+	#[cfg(feature = "reference")]
+	pub fn reference(&self, parsed: &dyn Any, part: Part) -> Answer {
+		match part {
+			Part::One => (self.reference_part_1)(parsed),
+			Part::Two => (self.reference_part_2)(parsed),
+		}
+	}
+	// Parses and solves the input, timing each phase and counting its allocations.
+	// A parse error or a parser that panics is recorded against every part and a part
+	// that panics is recorded as an error so the other days still run.
+	pub fn run(&self, input: &str, parts: &[Part]) -> Vec<Record> {
+		let mut records = Vec::with_capacity(parts.len());
+		self.run_each(input, parts, &mut |record| records.push(record));
+		records
+	}
+	// The same as run but each part's record is passed on as soon as it is solved.
+	pub fn run_each(&self, input: &str, parts: &[Part], on_record: &mut dyn FnMut(Record)) {
+		let now = Instant::now();
+		let (parsed, parse_memory) =
+			memory::measure(|| panic::catch_unwind(AssertUnwindSafe(|| (self.parse)(input))));
+		let parse = now.elapsed();
+		let error = match parsed {
+			Ok(Ok(parsed)) => Ok(parsed),
+			Ok(Err(e)) => Err(e.to_string()),
+			Err(payload) => Err(panic_message(payload.as_ref())),
+		};
+		let parsed = match error {
+			Ok(parsed) => parsed,
+			Err(e) => {
+				for record in Record::errors(self, parts, &e) {
+					on_record(Record {
+						parse,
+						parse_memory,
+						..record
+					});
+				}
+				return;
+			}
+		};
+		for part in parts.iter() {
+			let now = Instant::now();
+			let (answer, solve_memory) = memory::measure(|| {
+				panic::catch_unwind(AssertUnwindSafe(|| self.solve(parsed.as_ref(), *part)))
+			});
+			let solve = now.elapsed();
+			on_record(Record {
+				parse,
+				solve,
+				parse_memory,
+				solve_memory,
+				..Record::new(self, *part, answer.map_err(|e| panic_message(e.as_ref())))
+			});
+		}
+	}
+}
+
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+	let message = match (
+		payload.downcast_ref::<&str>(),
+		payload.downcast_ref::<String>(),
+	) {
+		(Some(message), _) => message,
+		(_, Some(message)) => message.as_str(),
+		_ => "unknown reason",
+	};
+	format!("panicked: {}", message)
+}
+
+fn parse<C>(input: &str) -> Result<Parsed, ParseError>
+where
+	C: ChallengeT + Send + 'static,
+{
+	Ok(Box::new(C::from_input(input)?))
+}
+fn part_1<C>(parsed: &dyn Any) -> Answer
+where
+	C: ChallengeT + 'static,
+{
+	downcast::<C>(parsed).part_1()
+}
+fn part_2<C>(parsed: &dyn Any) -> Answer
+where
+	C: ChallengeT + 'static,
+{
+	downcast::<C>(parsed).part_2()
+}
+#[cfg(feature = "reference")]
+fn reference_part_1<C>(parsed: &dyn Any) -> Answer
+where
+	C: ChallengeT + 'static,
+{
+	downcast::<C>(parsed).reference_part_1()
+}
+#[cfg(feature = "reference")]
+fn reference_part_2<C>(parsed: &dyn Any) -> Answer
+where
+	C: ChallengeT + 'static,
+{
+	downcast::<C>(parsed).reference_part_2()
+}
+fn downcast<C>(parsed: &dyn Any) -> &C
+where
+	C: ChallengeT + 'static,
+{
+	parsed
+		.downcast_ref::<C>()
+		.unwrap_or_else(|| panic!("Parsed input does not belong to day {}", C::day()))
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Part {
+	One,
+	Two,
+}
+impl Part {
+	pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+	pub fn number(&self) -> u8 {
+		match self {
+			Part::One => 1,
+			Part::Two => 2,
+		}
+	}
+}
+
+// Every year's days, each year lists its own days in its module.
+pub static YEARS: &[&[Entry]] = &[crate::year_2020::DAYS];
+
+pub fn all() -> impl Iterator<Item = &'static Entry> {
+	YEARS.iter().flat_map(|days| days.iter())
+}
+pub fn find(year: u16, day: u8) -> Option<&'static Entry> {
+	all().find(|entry| entry.year == year && entry.day == day)
+}
+pub fn select(selectors: &[Selector]) -> Vec<&'static Entry> {
+	all()
+		.filter(|entry| selectors.iter().any(|selector| selector.matches(entry)))
+		.collect()
+}
+
+// "<year>", "<year>/<days>" or just "<days>" for those days in every year,
+// where the days are a single day "7" or an inclusive range "1-10".
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selector {
+	pub year: Option<u16>,
+	pub days: RangeInclusive<u8>,
+}
+impl Selector {
+	pub const ALL: Selector = Selector {
+		year: None,
+		days: 1..=25,
+	};
+
+	pub fn parse(arg: &str) -> Option<Self> {
+		let (year, days) = match arg.split_once('/') {
+			Some((year, days)) => (Some(year.parse::<u16>().ok()?), Some(days)),
+			None => match arg.parse::<u16>() {
+				Ok(year) if year > 25 => (Some(year), None),
+				_ => (None, Some(arg)),
+			},
+		};
+		if year.is_some_and(|year| all().all(|entry| entry.year != year)) {
+			return None;
+		}
+		let days = match days {
+			Some(days) => {
+				let (start, end) = days.split_once('-').unwrap_or((days, days));
+				start.parse::<u8>().ok()?..=end.parse::<u8>().ok()?
+			}
+			None => 1..=25,
+		};
+		if days.is_empty() || *days.start() < 1 || *days.end() > 25 {
+			return None;
+		}
+		Some(Self { year, days })
+	}
+	pub fn matches(&self, entry: &Entry) -> bool {
+		self.year.is_none_or(|year| year == entry.year) && self.days.contains(&entry.day)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	#[cfg(feature = "reference")]
+	use crate::common::differential::assert_reference;
+	use crate::common::example::assert_examples;
+	use crate::common::generator::assert_generated;
+	#[test]
+	fn registry_test() {
+		for days in YEARS.iter() {
+			for (i, entry) in days.iter().enumerate() {
+				assert_eq!((entry.year, entry.day as usize), (days[0].year, i + 1));
+			}
+		}
+		let day_1 = find(2020, 1).unwrap();
+		let parsed = (day_1.parse)("1721\n979\n366\n299\n675\n1456").unwrap();
+		assert_eq!(day_1.solve(parsed.as_ref(), Part::One), 514579);
+		assert_eq!(day_1.solve(parsed.as_ref(), Part::Two), 241861950);
+	}
+	#[test]
+	fn panic_test() {
+		let entry = Entry {
+			parse: |_| panic!("unreadable"),
+			..Entry::new::<crate::year_2020::day_1::Challenge>(2020, 1, "Report Repair")
+		};
+		let records = entry.run("1721", &Part::BOTH);
+		assert_eq!(records.len(), 2);
+		for record in records.iter() {
+			assert_eq!(record.answer, Err(String::from("panicked: unreadable")));
+		}
+	}
+	// Every registered day is checked on its examples and generated inputs here instead
+	// of in each day's tests, which only have the checks particular to that day.
+	// A new day from the template has no examples until they are added.
+	#[test]
+	fn examples_test() {
+		for entry in all().filter(|entry| !entry.examples.is_empty()) {
+			assert_examples(entry);
+		}
+	}
+	#[test]
+	fn generated_test() {
+		for entry in all() {
+			assert_generated(entry);
+		}
+	}
+	#[cfg(feature = "reference")]
+	#[test]
+	fn reference_test() {
+		for entry in all() {
+			assert_reference(entry);
+		}
+	}
+	#[test]
+	fn selector_test() {
+		let selector = |arg| Selector::parse(arg).map(|s| (s.year, s.days));
+		assert_eq!(selector("17"), Some((None, 17..=17)));
+		assert_eq!(selector("1-10"), Some((None, 1..=10)));
+		assert_eq!(selector("2020"), Some((Some(2020), 1..=25)));
+		assert_eq!(selector("2020/17"), Some((Some(2020), 17..=17)));
+		assert_eq!(selector("2020/15-25"), Some((Some(2020), 15..=25)));
+		assert_eq!(selector("1999/1"), None);
+		assert_eq!(selector("2020/26"), None);
+		assert_eq!(selector("10-1"), None);
+		assert_eq!(selector("0"), None);
+		assert_eq!(select(&[Selector::parse("2020/3-5").unwrap()]).len(), 3);
+	}
+}
@@ -0,0 +1,303 @@
+// File: common/repl.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use super::registry::{self, Entry, Part, Selector};
+use super::report::{Format, Writer};
+use super::runner::InputSource;
+
+// The name, arguments and description of each command.
+const COMMANDS: [(&str, &str, &str); 10] = [
+	("days", "", "lists every day"),
+	(
+		"day",
+		"<day>",
+		"chooses the day the other commands use, like 15 or 2020/15",
+	),
+	(
+		"load",
+		"[file]",
+		"reads the day's input from the file or the inputs directory",
+	),
+	(
+		"input",
+		"<text>",
+		"uses the text as the day's input, \\n starts a new line",
+	),
+	(
+		"run",
+		"[part]",
+		"solves both parts of the day's input or just the one",
+	),
+	("queries", "", "lists the day's queries"),
+	(
+		"query",
+		"<name> [arguments]",
+		"calls one of the day's queries",
+	),
+	("history", "", "lists the commands entered so far"),
+	("help", "", "lists the commands"),
+	("quit", "", "leaves the repl"),
+];
+
+// The state of the "repl" command, which day is chosen and its input.
+#[derive(Default)]
+pub struct Session {
+	entry: Option<&'static Entry>,
+	input: Option<String>,
+	pub history: Vec<String>,
+}
+impl Session {
+	pub fn new() -> Self {
+		Self::default()
+	}
+	pub fn prompt(&self) -> String {
+		match self.entry {
+			Some(entry) => format!("{}/{}> ", entry.year, entry.day),
+			None => String::from("> "),
+		}
+	}
+	// Runs a line and returns what to print, or None once the session is over.
+	pub fn execute(&mut self, line: &str) -> Option<String> {
+		let line = line.trim();
+		if line.is_empty() {
+			return Some(String::new());
+		}
+		if self.history.last().map(String::as_str) != Some(line) {
+			self.history.push(line.to_string());
+		}
+		let (command, arguments) = line.split_once(' ').unwrap_or((line, ""));
+		let arguments = arguments.trim();
+		let output = match command {
+			"days" => Ok(days()),
+			"day" => self.choose(arguments),
+			"load" => self.load(arguments),
+			"input" => self.set_input(arguments.replace("\\n", "\n")),
+			"run" => self.run(arguments),
+			"queries" => self.queries(),
+			"query" => self.query(arguments),
+			"history" => Ok(self
+				.history
+				.iter()
+				.enumerate()
+				.map(|(i, line)| format!("{:>4}  {}\n", i + 1, line))
+				.collect()),
+			"help" => Ok(help()),
+			"quit" | "exit" => return None,
+			_ => Err(format!("unknown command \"{}\", try help", command)),
+		};
+		Some(output.unwrap_or_else(|e| format!("error: {}\n", e)))
+	}
+	// The index the word being completed starts at and what it could be.
+	pub fn complete(&self, line: &str) -> (usize, Vec<String>) {
+		let start = line.rfind(' ').map_or(0, |i| i + 1);
+		let word = &line[start..];
+		let before = line[..start].split_whitespace().collect::<Vec<_>>();
+		let candidates = match before[..] {
+			[] => COMMANDS
+				.iter()
+				.map(|(name, _, _)| name.to_string())
+				.collect(),
+			["day"] => registry::all()
+				.flat_map(|entry| {
+					vec![
+						format!("{}/{}", entry.year, entry.day),
+						entry.day.to_string(),
+					]
+				})
+				.collect(),
+			["run"] => vec![String::from("1"), String::from("2")],
+			["query"] => self
+				.entry
+				.iter()
+				.flat_map(|entry| entry.queries.iter())
+				.map(|query| query.name.to_string())
+				.collect(),
+			_ => Vec::new(),
+		};
+		let mut candidates = candidates
+			.into_iter()
+			.filter(|candidate| candidate.starts_with(word))
+			.collect::<Vec<_>>();
+		candidates.dedup();
+		(start, candidates)
+	}
+
+	fn entry(&self) -> Result<&'static Entry, String> {
+		self.entry
+			.ok_or_else(|| String::from("no day chosen, try day <day>"))
+	}
+	fn choose(&mut self, arguments: &str) -> Result<String, String> {
+		let selector = Selector::parse(arguments)
+			.filter(|selector| selector.days.start() == selector.days.end())
+			.ok_or_else(|| format!("\"{}\" is not a day", arguments))?;
+		let entry = match registry::select(&[selector])[..] {
+			[entry] => entry,
+			[] => return Err(format!("there is no day {}", arguments)),
+			_ => return Err(format!("day {} is in more than one year", arguments)),
+		};
+		if self.entry.map(|chosen| (chosen.year, chosen.day)) != Some((entry.year, entry.day)) {
+			self.entry = Some(entry);
+			self.input = None;
+		}
+		Ok(format!("{}/{} {}\n", entry.year, entry.day, entry.title))
+	}
+	fn load(&mut self, arguments: &str) -> Result<String, String> {
+		let entry = self.entry()?;
+		let source = match arguments {
+			"" => InputSource::default(),
+			path => InputSource::File(PathBuf::from(path)),
+		};
+		let input = source
+			.read(entry.year, entry.day)
+			.map_err(|e| e.to_string())?;
+		self.set_input(input)
+	}
+	// The input is parsed right away so a mistake shows up before it is run.
+	fn set_input(&mut self, input: String) -> Result<String, String> {
+		let entry = self.entry()?;
+		(entry.parse)(&input).map_err(|e| e.to_string())?;
+		let lines = input.lines().count();
+		self.input = Some(input);
+		Ok(format!("{} lines\n", lines))
+	}
+	fn run(&self, arguments: &str) -> Result<String, String> {
+		let entry = self.entry()?;
+		let input = self
+			.input
+			.as_ref()
+			.ok_or_else(|| String::from("no input, try load or input <text>"))?;
+		let parts = match arguments {
+			"" => Part::BOTH.to_vec(),
+			"1" => vec![Part::One],
+			"2" => vec![Part::Two],
+			_ => return Err(format!("\"{}\" is not a part", arguments)),
+		};
+		Ok(Writer::new(Format::Text).day(&entry.run(input, &parts)))
+	}
+	fn queries(&self) -> Result<String, String> {
+		let entry = self.entry()?;
+		if entry.queries.is_empty() {
+			return Ok(format!("{}/{} has no queries\n", entry.year, entry.day));
+		}
+		let mut out = String::new();
+		for query in entry.queries.iter() {
+			let usage = format!("{} {}", query.name, query.usage);
+			writeln!(out, "{:<32} {}", usage, query.description).unwrap();
+		}
+		Ok(out)
+	}
+	fn query(&self, arguments: &str) -> Result<String, String> {
+		let entry = self.entry()?;
+		let arguments = arguments.split_whitespace().collect::<Vec<_>>();
+		let (name, arguments) = arguments
+			.split_first()
+			.ok_or_else(|| String::from("query expects a name, try queries"))?;
+		let query = entry
+			.queries
+			.iter()
+			.find(|query| query.name == *name)
+			.ok_or_else(|| format!("{}/{} has no query \"{}\"", entry.year, entry.day, name))?;
+		match (query.run)(arguments) {
+			Ok(answer) => Ok(format!("{}\n", answer)),
+			Err(e) => Err(format!("{}, usage: {} {}", e, query.name, query.usage)),
+		}
+	}
+}
+
+fn days() -> String {
+	let mut out = String::new();
+	for entry in registry::all() {
+		writeln!(
+			out,
+			"{:<8} {}",
+			format!("{}/{}", entry.year, entry.day),
+			entry.title
+		)
+		.unwrap();
+	}
+	out
+}
+fn help() -> String {
+	let mut out = String::new();
+	for (name, arguments, description) in COMMANDS.iter() {
+		let usage = format!("{} {}", name, arguments);
+		writeln!(out, "{:<28} {}", usage, description).unwrap();
+	}
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	#[test]
+	fn session_test() {
+		let mut session = Session::new();
+		let mut execute = |line: &str| session.execute(line).unwrap();
+		assert!(execute("run").starts_with("error: no day"));
+		assert_eq!(execute("day 2020/15"), "2020/15 Rambunctious Recitation\n");
+		assert_eq!(execute("query play 0,3,6 2020"), "436\n");
+		assert!(execute("query play 0,3,6").starts_with("error: "));
+		assert!(execute("query unknown").starts_with("error: "));
+		assert_eq!(execute("input 1,3,2"), "1 lines\n");
+		assert!(execute("run 1").contains("part 1: 1 "));
+		assert_eq!(execute("day 18"), "2020/18 Operation Order\n");
+		assert_eq!(execute("query advanced 2 * 3 + (4 * 5)"), "46\n");
+		assert!(execute("day 26").starts_with("error: "));
+		assert!(execute("jump").starts_with("error: unknown command"));
+		assert_eq!(session.execute("quit"), None);
+		assert_eq!(session.history.len(), 12);
+	}
+	#[test]
+	fn complete_test() {
+		let mut session = Session::new();
+		assert_eq!(
+			session.complete("qu"),
+			(
+				0,
+				vec![
+					String::from("queries"),
+					String::from("query"),
+					String::from("quit")
+				]
+			)
+		);
+		let (start, candidates) = session.complete("day 2020/1");
+		assert_eq!((start, candidates.len()), (4, 11));
+		assert_eq!(
+			session.complete("day 2020/25").1,
+			vec![String::from("2020/25")]
+		);
+		assert_eq!(session.complete("query p"), (6, Vec::new()));
+		session.execute("day 15");
+		assert_eq!(session.complete("query p"), (6, vec![String::from("play")]));
+		assert_eq!(
+			session.complete("run "),
+			(4, vec![String::from("1"), String::from("2")])
+		);
+	}
+}
@@ -0,0 +1,468 @@
+// File: common/report.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use super::answers::{Answer, Expected};
+#[cfg(feature = "reference")]
+use super::differential;
+use super::memory::Allocations;
+use super::registry::{Entry, Part};
+use super::runner::{Check, ThreadUsage};
+
+// One answer from one part of a day. Errors are kept as records so a failed day still shows up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+	pub year: u16,
+	pub day: u8,
+	pub title: &'static str,
+	pub part: Part,
+	pub answer: Result<Answer, String>,
+	pub parse: Duration,
+	pub solve: Duration,
+	// Only counted with the "count-allocations" feature.
+	pub parse_memory: Option<Allocations>,
+	pub solve_memory: Option<Allocations>,
+}
+impl Record {
+	pub fn new(entry: &Entry, part: Part, answer: Result<Answer, String>) -> Self {
+		Self {
+			year: entry.year,
+			day: entry.day,
+			title: entry.title,
+			part,
+			answer,
+			parse: Duration::default(),
+			solve: Duration::default(),
+			parse_memory: None,
+			solve_memory: None,
+		}
+	}
+	pub fn errors(entry: &Entry, parts: &[Part], error: &str) -> Vec<Self> {
+		parts
+			.iter()
+			.map(|part| Self::new(entry, *part, Err(error.to_string())))
+			.collect()
+	}
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Format {
+	Text,
+	Json,
+	Csv,
+}
+impl Format {
+	pub fn from_arg(arg: &str) -> Option<Self> {
+		match arg {
+			"text" => Some(Format::Text),
+			"json" => Some(Format::Json),
+			"csv" => Some(Format::Csv),
+			_ => None,
+		}
+	}
+	pub fn is_text(&self) -> bool {
+		*self == Format::Text
+	}
+}
+
+// Writes records as they are produced so long runs still print each day when it finishes.
+// Durations are whole nanoseconds and fields are always in the same order.
+pub struct Writer {
+	format: Format,
+	written: usize,
+}
+impl Writer {
+	pub fn new(format: Format) -> Self {
+		Self { format, written: 0 }
+	}
+	pub fn begin(&self) -> String {
+		match self.format {
+			Format::Text => String::new(),
+			Format::Json => String::from("[\n"),
+			Format::Csv => String::from("year,day,part,answer,error,parse_ns,solve_ns,parse_peak_bytes,parse_total_bytes,solve_peak_bytes,solve_total_bytes\n"),
+		}
+	}
+	// The records of a single day.
+	pub fn day(&mut self, records: &[Record]) -> String {
+		let mut out = String::new();
+		match self.format {
+			Format::Text => write_text(&mut out, records),
+			Format::Json => {
+				for record in records.iter() {
+					if self.written > 0 {
+						out.push_str(",\n");
+					}
+					write_json(&mut out, record);
+					self.written += 1;
+				}
+			}
+			Format::Csv => {
+				for record in records.iter() {
+					write_csv(&mut out, record);
+				}
+			}
+		}
+		out
+	}
+	pub fn end(&self) -> String {
+		match self.format {
+			Format::Json if self.written > 0 => String::from("\n]\n"),
+			Format::Json => String::from("]\n"),
+			_ => String::new(),
+		}
+	}
+}
+
+fn write_text(out: &mut String, records: &[Record]) {
+	let first = match records.first() {
+		Some(record) => record,
+		None => return,
+	};
+	writeln!(out, "{} Day {}: {}", first.year, first.day, first.title).unwrap();
+	if records.iter().any(|record| record.answer.is_ok()) {
+		writeln!(
+			out,
+			"  parse: {}{}",
+			milliseconds(first.parse),
+			memory(first.parse_memory)
+		)
+		.unwrap();
+	}
+	let mut last_error = None;
+	for record in records.iter() {
+		match &record.answer {
+			Ok(answer) => writeln!(
+				out,
+				"  part {}: {} ({}{})",
+				record.part.number(),
+				answer,
+				milliseconds(record.solve),
+				memory(record.solve_memory)
+			)
+			.unwrap(),
+			Err(e) if last_error != Some(e) => {
+				writeln!(out, "  error: {}", e).unwrap();
+				last_error = Some(e);
+			}
+			Err(_) => (),
+		}
+	}
+}
+pub fn milliseconds(duration: Duration) -> String {
+	format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+pub fn bytes(bytes: u64) -> String {
+	const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+	if bytes < 1024 {
+		return format!("{}B", bytes);
+	}
+	let mut size = bytes as f64 / 1024.0;
+	let mut unit = 0;
+	while size >= 1024.0 && unit + 1 < UNITS.len() {
+		size /= 1024.0;
+		unit += 1;
+	}
+	format!("{:.1}{}", size, UNITS[unit])
+}
+fn memory(allocations: Option<Allocations>) -> String {
+	allocations.map_or_else(String::new, |allocations| {
+		format!(
+			", peak {}, total {}",
+			bytes(allocations.peak),
+			bytes(allocations.total)
+		)
+	})
+}
+fn write_json(out: &mut String, record: &Record) {
+	let (answer, error) = match &record.answer {
+		Ok(answer) => (json_answer(answer), String::from("null")),
+		Err(e) => (String::from("null"), json_string(e)),
+	};
+	let memory = memory_fields(record, "null");
+	write!(
+		out,
+		"  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"error\": {}, \"parse_ns\": {}, \"solve_ns\": {}, \"parse_peak_bytes\": {}, \"parse_total_bytes\": {}, \"solve_peak_bytes\": {}, \"solve_total_bytes\": {}}}",
+		record.year,
+		record.day,
+		record.part.number(),
+		answer,
+		error,
+		record.parse.as_nanos(),
+		record.solve.as_nanos(),
+		memory[0],
+		memory[1],
+		memory[2],
+		memory[3]
+	)
+	.unwrap();
+}
+// Integers are numbers, a part without an answer is null and everything else is a string.
+// Integers past 2^53 are strings too as most JSON readers keep numbers as doubles, which
+// can't hold them exactly.
+fn json_answer(answer: &Answer) -> String {
+	const MAX_EXACT: u128 = 1 << 53;
+	match answer {
+		Answer::Integer(n) if (*n as i128).unsigned_abs() <= MAX_EXACT => answer.to_string(),
+		Answer::BigInteger(n) if n.unsigned_abs() <= MAX_EXACT => answer.to_string(),
+		Answer::Integer(_) | Answer::BigInteger(_) => json_string(&answer.to_string()),
+		Answer::String(s) => json_string(s),
+		Answer::NotApplicable => String::from("null"),
+	}
+}
+fn json_string(s: &str) -> String {
+	let mut out = String::from("\"");
+	for c in s.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+	out
+}
+fn write_csv(out: &mut String, record: &Record) {
+	let (answer, error) = match &record.answer {
+		Ok(answer) => (csv_field(&answer.to_string()), String::new()),
+		Err(e) => (String::new(), csv_field(e)),
+	};
+	let memory = memory_fields(record, "");
+	writeln!(
+		out,
+		"{},{},{},{},{},{},{},{},{},{},{}",
+		record.year,
+		record.day,
+		record.part.number(),
+		answer,
+		error,
+		record.parse.as_nanos(),
+		record.solve.as_nanos(),
+		memory[0],
+		memory[1],
+		memory[2],
+		memory[3]
+	)
+	.unwrap();
+}
+// Peak and total bytes of the parse and then the solve, missing is used when they were not counted.
+fn memory_fields(record: &Record, missing: &str) -> [String; 4] {
+	let fields = |allocations: Option<Allocations>| match allocations {
+		Some(allocations) => [allocations.peak.to_string(), allocations.total.to_string()],
+		None => [missing.to_string(), missing.to_string()],
+	};
+	let [parse_peak, parse_total] = fields(record.parse_memory);
+	let [solve_peak, solve_total] = fields(record.solve_memory);
+	[parse_peak, parse_total, solve_peak, solve_total]
+}
+fn csv_field(s: &str) -> String {
+	if s.contains([',', '"', '\n']) {
+		format!("\"{}\"", s.replace('"', "\"\""))
+	} else {
+		s.to_string()
+	}
+}
+
+// The pass/fail table of checked answers. The input and answer columns are as wide as
+// their longest entry so the rows line up as they are printed.
+pub struct CheckTable {
+	input_width: usize,
+	answer_width: usize,
+}
+impl CheckTable {
+	pub fn new(expected: &[Expected]) -> Self {
+		let width = |len: &dyn Fn(&Expected) -> usize| expected.iter().map(len).max().unwrap_or(0);
+		Self {
+			input_width: width(&|answer| answer.input.len()).max(5),
+			answer_width: width(&|answer| answer.answer.to_string().len()).max(8),
+		}
+	}
+	pub fn header(&self) -> String {
+		format!(
+			"{:<iw$}  {:<7} {:<4} {:<aw$}  {:<aw$}  result\n",
+			"input",
+			"day",
+			"part",
+			"expected",
+			"actual",
+			iw = self.input_width,
+			aw = self.answer_width
+		)
+	}
+	pub fn row(&self, check: &Check) -> String {
+		let actual = match &check.actual {
+			Ok(actual) => actual.to_string(),
+			Err(e) => e.clone(),
+		};
+		format!(
+			"{:<iw$}  {:<7} {:<4} {:<aw$}  {:<aw$}  {}\n",
+			check.expected.input,
+			format!("{}/{}", check.expected.year, check.expected.day),
+			check.expected.part.number(),
+			check.expected.answer,
+			actual,
+			if check.passed() { "pass" } else { "FAIL" },
+			iw = self.input_width,
+			aw = self.answer_width
+		)
+	}
+}
+pub fn passed(passed: usize, failed: usize) -> String {
+	format!("{} passed, {} failed", passed, failed)
+}
+
+// The line ending the problems found in a day's input.
+pub fn lint_summary(entry: &Entry, problems: usize) -> String {
+	match problems {
+		0 => format!("{}/{}: ok\n", entry.year, entry.day),
+		1 => format!("{}/{}: 1 problem\n", entry.year, entry.day),
+		n => format!("{}/{}: {} problems\n", entry.year, entry.day, n),
+	}
+}
+
+#[cfg(feature = "reference")]
+pub fn skipped_part(entry: &Entry, part: Part) -> String {
+	format!(
+		"{}/{}: part {} skipped as it is too slow to compare\n",
+		entry.year,
+		entry.day,
+		part.number()
+	)
+}
+// A fast answer that differs from the reference one, either of them may be an error.
+#[cfg(feature = "reference")]
+pub fn difference(comparison: &differential::Comparison) -> String {
+	let answer = |answer: &Result<Answer, String>| {
+		answer
+			.as_ref()
+			.map_or_else(String::clone, ToString::to_string)
+	};
+	format!(
+		"{}/{} {} part {}: fast {} but reference {}\n",
+		comparison.year,
+		comparison.day,
+		comparison.input,
+		comparison.part.number(),
+		answer(&comparison.fast),
+		answer(&comparison.reference)
+	)
+}
+
+// A day that could not be benchmarked, in place of its rows in the table.
+pub fn bench_error(entry: &Entry, e: &str) -> String {
+	format!("{:<5} {:<4} error: {}\n", entry.year, entry.day, e)
+}
+// How busy each thread was during the elapsed time of a threaded run.
+pub fn thread_usage(utilization: &[ThreadUsage], elapsed: Duration) -> String {
+	let mut out = String::new();
+	for (i, usage) in utilization.iter().enumerate() {
+		writeln!(
+			out,
+			"th{}: {} days, busy {}ms of {}ms ({:.1}%)",
+			i + 1,
+			usage.days,
+			usage.busy.as_millis(),
+			elapsed.as_millis(),
+			usage.busy.as_secs_f64() / elapsed.as_secs_f64() * 100.0
+		)
+		.unwrap();
+	}
+	out
+}
+pub fn estimated_time(elapsed: Duration) -> String {
+	format!(
+		"Estemated Time: {}ms or {}ns\n",
+		elapsed.as_millis(),
+		elapsed.as_nanos()
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::common::registry;
+	#[test]
+	fn format_test() {
+		let day_21 = registry::find(2020, 21).unwrap();
+		let records = vec![
+			Record {
+				parse_memory: Some(Allocations {
+					peak: 10,
+					total: 20,
+				}),
+				solve_memory: Some(Allocations {
+					peak: 1536,
+					total: 3 << 20,
+				}),
+				..Record::new(day_21, Part::One, Ok(Answer::Integer(5)))
+			},
+			Record::new(day_21, Part::Two, Ok(Answer::from("mxmxvkd,\"sqjhc\""))),
+		];
+		let mut json = Writer::new(Format::Json);
+		assert_eq!(
+			json.begin() + &json.day(&records) + &json.end(),
+			"[\n  {\"year\": 2020, \"day\": 21, \"part\": 1, \"answer\": 5, \"error\": null, \"parse_ns\": 0, \"solve_ns\": 0, \"parse_peak_bytes\": 10, \"parse_total_bytes\": 20, \"solve_peak_bytes\": 1536, \"solve_total_bytes\": 3145728},\n  {\"year\": 2020, \"day\": 21, \"part\": 2, \"answer\": \"mxmxvkd,\\\"sqjhc\\\"\", \"error\": null, \"parse_ns\": 0, \"solve_ns\": 0, \"parse_peak_bytes\": null, \"parse_total_bytes\": null, \"solve_peak_bytes\": null, \"solve_total_bytes\": null}\n]\n"
+		);
+		let mut csv = Writer::new(Format::Csv);
+		assert_eq!(
+			csv.begin() + &csv.day(&records) + &csv.end(),
+			"year,day,part,answer,error,parse_ns,solve_ns,parse_peak_bytes,parse_total_bytes,solve_peak_bytes,solve_total_bytes\n2020,21,1,5,,0,0,10,20,1536,3145728\n2020,21,2,\"mxmxvkd,\"\"sqjhc\"\"\",,0,0,,,,\n"
+		);
+		assert_eq!(
+			Writer::new(Format::Text).day(&records[..1]),
+			"2020 Day 21: Allergen Assessment\n  parse: 0.000ms, peak 10B, total 20B\n  part 1: 5 (0.000ms, peak 1.5KiB, total 3.0MiB)\n"
+		);
+		assert_eq!(
+			json_answer(&Answer::BigInteger(1 << 100)),
+			"\"1267650600228229401496703205376\""
+		);
+		assert_eq!(
+			json_answer(&Answer::BigInteger(-(1 << 53))),
+			"-9007199254740992"
+		);
+		assert_eq!(
+			json_answer(&Answer::Integer((1 << 53) + 1)),
+			"\"9007199254740993\""
+		);
+		let day_25 = registry::find(2020, 25).unwrap();
+		let not_applicable = [Record::new(day_25, Part::Two, Ok(Answer::NotApplicable))];
+		assert_eq!(
+			Writer::new(Format::Json).day(&not_applicable),
+			"  {\"year\": 2020, \"day\": 25, \"part\": 2, \"answer\": null, \"error\": null, \"parse_ns\": 0, \"solve_ns\": 0, \"parse_peak_bytes\": null, \"parse_total_bytes\": null, \"solve_peak_bytes\": null, \"solve_total_bytes\": null}"
+		);
+		assert_eq!(
+			Writer::new(Format::Csv).day(&not_applicable),
+			"2020,25,2,N/A,,0,0,,,,\n"
+		);
+		let errors = Record::errors(day_21, &Part::BOTH, "line 1, column 1: bad");
+		assert_eq!(
+			Writer::new(Format::Text).day(&errors),
+			"2020 Day 21: Allergen Assessment\n  error: line 1, column 1: bad\n"
+		);
+	}
+}
@@ -0,0 +1,569 @@
+// File: common/runner.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::answers::{self, Answer, Expected};
+use super::bench::{self, Phase, PhaseStats, Runs, Stats};
+#[cfg(feature = "reference")]
+use super::differential;
+use super::example;
+use super::history;
+use super::parse_error::ParseError;
+use super::registry::{self, Entry, Part};
+use super::report::{self, milliseconds, CheckTable, Format, Record, Writer};
+
+// Where the puzzle input for each day is read from.
+// A directory holds "<year>/day_<n>.txt" for each day, a file or stdin holds the input for a single day.
+pub enum InputSource {
+	Directory(PathBuf),
+	File(PathBuf),
+	Stdin,
+}
+impl InputSource {
+	pub fn from_arg(arg: &str) -> Self {
+		let path = PathBuf::from(arg);
+		if arg == "-" {
+			InputSource::Stdin
+		} else if path.is_dir() {
+			InputSource::Directory(path)
+		} else {
+			InputSource::File(path)
+		}
+	}
+	pub fn is_single_day(&self) -> bool {
+		!matches!(self, InputSource::Directory(_))
+	}
+	pub fn read(&self, year: u16, day: u8) -> io::Result<String> {
+		let path = match self {
+			InputSource::Directory(directory) => directory
+				.join(year.to_string())
+				.join(format!("day_{}.txt", day)),
+			InputSource::File(path) => path.clone(),
+			InputSource::Stdin => {
+				let mut input = String::new();
+				io::stdin().read_to_string(&mut input)?;
+				return Ok(input);
+			}
+		};
+		fs::read_to_string(&path)
+			.map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+	}
+}
+impl Default for InputSource {
+	fn default() -> Self {
+		InputSource::Directory(PathBuf::from("inputs"))
+	}
+}
+
+// Errors are reported in place of the answers so the remaining days still run.
+pub fn run_day(
+	entry: &'static Entry,
+	parts: &[Part],
+	input: io::Result<String>,
+	timeout: Option<Duration>,
+) -> Vec<Record> {
+	match (input, timeout) {
+		(Ok(input), None) => entry.run(&input, parts),
+		(Ok(input), Some(timeout)) => run_with_timeout(entry, parts, input, timeout),
+		(Err(e), _) => Record::errors(entry, parts, &e.to_string()),
+	}
+}
+// The day runs on its own thread. Threads can't be stopped from the outside so a day that
+// runs out of time is left running in the background, detached, until it finishes or the
+// program exits. The parts it solved in time are kept and the rest are marked as timed out.
+fn run_with_timeout(
+	entry: &'static Entry,
+	parts: &[Part],
+	input: String,
+	timeout: Duration,
+) -> Vec<Record> {
+	let deadline = Instant::now() + timeout;
+	let (sender, receiver) = mpsc::channel();
+	let day_parts = parts.to_vec();
+	let spawned = thread::Builder::new()
+		.name(format!("{}/{}", entry.year, entry.day))
+		.spawn(move || {
+			entry.run_each(&input, &day_parts, &mut |record| {
+				// The receiver is gone once the day has timed out.
+				let _ = sender.send(record);
+			})
+		});
+	if let Err(e) = spawned {
+		return Record::errors(entry, parts, &e.to_string());
+	}
+
+	let mut records = Vec::with_capacity(parts.len());
+	let error = loop {
+		if records.len() == parts.len() {
+			return records;
+		}
+		match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+			Ok(record) => records.push(record),
+			Err(RecvTimeoutError::Timeout) => {
+				break format!("timed out after {}", milliseconds(timeout))
+			}
+			Err(RecvTimeoutError::Disconnected) => break String::from("stopped without an answer"),
+		}
+	};
+	let parse = records
+		.first()
+		.map_or(Duration::default(), |record| record.parse);
+	for part in parts[records.len()..].iter() {
+		records.push(Record {
+			parse,
+			..Record::new(entry, *part, Err(error.clone()))
+		});
+	}
+	records
+}
+
+pub type DayFn<'a> = dyn FnMut(&[Record]) + 'a;
+pub fn run_all(
+	entries: &[&'static Entry],
+	parts: &[Part],
+	source: &InputSource,
+	timeout: Option<Duration>,
+	on_day: &mut DayFn,
+) {
+	for entry in entries.iter() {
+		let input = source.read(entry.year, entry.day);
+		on_day(&run_day(entry, parts, input, timeout));
+	}
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct ThreadUsage {
+	pub days: usize,
+	pub busy: Duration,
+}
+// Each thread takes the next day as soon as it finishes one.
+// on_day still sees the days in order, as soon as every earlier day has finished.
+pub fn run_threaded(
+	entries: &[&'static Entry],
+	parts: &[Part],
+	source: &InputSource,
+	threads: usize,
+	timeout: Option<Duration>,
+	on_day: &mut DayFn,
+) -> Vec<ThreadUsage> {
+	let next_day = AtomicUsize::new(0);
+	let (sender, receiver) = mpsc::channel();
+	thread::scope(|scope| {
+		let handles = (0..threads.min(entries.len()))
+			.map(|_| {
+				let sender = sender.clone();
+				let next_day = &next_day;
+				scope.spawn(move || {
+					let mut usage = ThreadUsage::default();
+					loop {
+						let i = next_day.fetch_add(1, Ordering::Relaxed);
+						let entry = match entries.get(i) {
+							Some(entry) => entry,
+							None => break,
+						};
+						let now = Instant::now();
+						let input = source.read(entry.year, entry.day);
+						let records = run_day(entry, parts, input, timeout);
+						usage.busy += now.elapsed();
+						usage.days += 1;
+						sender.send((i, records)).unwrap();
+					}
+					usage
+				})
+			})
+			.collect::<Vec<_>>();
+		drop(sender);
+
+		let mut finished = vec![None; entries.len()];
+		let mut next_to_report = 0;
+		for (i, records) in receiver {
+			finished[i] = Some(records);
+			while let Some(records) = finished.get_mut(next_to_report).and_then(Option::take) {
+				on_day(&records);
+				next_to_report += 1;
+			}
+		}
+
+		handles
+			.into_iter()
+			.map(|handle| handle.join().unwrap())
+			.collect()
+	})
+}
+
+pub type StatsFn<'a> = dyn FnMut(&Entry, Result<&[PhaseStats], &str>) + 'a;
+// Benchmarks each day in order, passing the results of each day to on_day as it finishes.
+pub fn bench_days(
+	entries: &[&Entry],
+	parts: &[Part],
+	source: &InputSource,
+	runs: Runs,
+	on_day: &mut StatsFn,
+) -> Vec<PhaseStats> {
+	let mut all_stats = Vec::new();
+	for entry in entries.iter() {
+		let stats = source
+			.read(entry.year, entry.day)
+			.map_err(|e| e.to_string())
+			.and_then(|input| bench::bench(entry, &input, parts, runs.iterations, runs.warmup));
+		match stats {
+			Ok(stats) => {
+				on_day(entry, Ok(&stats));
+				all_stats.extend(stats);
+			}
+			Err(e) => on_day(entry, Err(&e)),
+		}
+	}
+	all_stats
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check<'a> {
+	pub expected: &'a Expected,
+	pub actual: Result<Answer, String>,
+}
+impl Check<'_> {
+	pub fn passed(&self) -> bool {
+		self.actual.as_ref() == Ok(&self.expected.answer)
+	}
+}
+// Inputs are found relative to directory and each input is only solved once per day.
+pub fn check_answers<'a>(
+	expected: &'a [Expected],
+	directory: &Path,
+	on_check: &mut dyn FnMut(&Check),
+) -> Vec<Check<'a>> {
+	let mut groups = Vec::<((&str, u16, u8), Vec<&Expected>)>::new();
+	for answer in expected.iter() {
+		let key = (answer.input.as_str(), answer.year, answer.day);
+		match groups.iter_mut().find(|(group, _)| *group == key) {
+			Some((_, answers)) => answers.push(answer),
+			None => groups.push((key, vec![answer])),
+		}
+	}
+	let mut checks = Vec::new();
+	for ((input, year, day), answers) in groups.into_iter() {
+		let parts = answers.iter().map(|answer| answer.part).collect::<Vec<_>>();
+		let records = match registry::find(year, day) {
+			Some(entry) => {
+				let source = InputSource::from_arg(&directory.join(input).to_string_lossy());
+				run_day(entry, &parts, source.read(year, day), None)
+			}
+			None => Vec::new(),
+		};
+		for (i, answer) in answers.into_iter().enumerate() {
+			let check = Check {
+				expected: answer,
+				actual: records.get(i).map_or_else(
+					|| Err(format!("unknown day {}/{}", year, day)),
+					|record| record.answer.clone(),
+				),
+			};
+			on_check(&check);
+			checks.push(check);
+		}
+	}
+	checks
+}
+
+pub type OutFn<'a> = dyn FnMut(&str) + 'a;
+// The commands below write their tables to out as each day finishes.
+// In the text format a run ends with how long it took.
+pub fn write_all(
+	entries: &[&'static Entry],
+	parts: &[Part],
+	source: &InputSource,
+	format: Format,
+	timeout: Option<Duration>,
+	out: &mut OutFn,
+) {
+	let now = Instant::now();
+	let mut writer = Writer::new(format);
+	out(&writer.begin());
+	run_all(entries, parts, source, timeout, &mut |records| {
+		out(&writer.day(records))
+	});
+	out(&writer.end());
+	if format.is_text() {
+		out(&report::estimated_time(now.elapsed()));
+	}
+}
+// The answers are written in day order followed by how busy each thread was.
+pub fn write_threaded(
+	entries: &[&'static Entry],
+	parts: &[Part],
+	source: &InputSource,
+	format: Format,
+	threads: usize,
+	timeout: Option<Duration>,
+	out: &mut OutFn,
+) {
+	let now = Instant::now();
+	let mut writer = Writer::new(format);
+	out(&writer.begin());
+	let utilization = run_threaded(entries, parts, source, threads, timeout, &mut |records| {
+		out(&writer.day(records))
+	});
+	out(&writer.end());
+	if format.is_text() {
+		let elapsed = now.elapsed();
+		out(&report::thread_usage(&utilization, elapsed));
+		out(&report::estimated_time(elapsed));
+	}
+}
+// Writes the timing statistics of every phase as each day finishes.
+pub fn bench_all(
+	entries: &[&Entry],
+	parts: &[Part],
+	source: &InputSource,
+	runs: Runs,
+	out: &mut OutFn,
+) -> Vec<PhaseStats> {
+	out(&format!("{}\n{}", runs, bench::table_header()));
+	bench_days(
+		entries,
+		parts,
+		source,
+		runs,
+		&mut |entry, stats| match stats {
+			Ok(stats) => out(&bench::table_rows(stats)),
+			Err(e) => out(&report::bench_error(entry, e)),
+		},
+	)
+}
+// Flags the phases whose median is more than threshold percent slower than the baseline.
+// The bool is false if any phase regressed or a day failed to run.
+pub fn compare_all(
+	entries: &[&Entry],
+	parts: &[Part],
+	source: &InputSource,
+	runs: Runs,
+	baseline: &HashMap<(u16, u8, Phase), (u64, Stats)>,
+	threshold: f64,
+	out: &mut OutFn,
+) -> (Vec<PhaseStats>, bool) {
+	out(&format!(
+		"{}, regressions are over {}%\n{}",
+		runs,
+		threshold,
+		history::table_header()
+	));
+	let mut passed = true;
+	let stats = bench_days(
+		entries,
+		parts,
+		source,
+		runs,
+		&mut |entry, stats| match stats {
+			Ok(stats) => {
+				let comparisons = history::compare(baseline, stats);
+				passed &= !comparisons
+					.iter()
+					.any(|comparison| comparison.regressed(threshold));
+				out(&history::table_rows(&comparisons, threshold));
+			}
+			Err(e) => {
+				passed = false;
+				out(&report::bench_error(entry, e));
+			}
+		},
+	);
+	(stats, passed)
+}
+// Checks every selected answer in the answers file and writes a pass/fail table.
+// Inputs are found relative to the answers file.
+pub fn verify_all(
+	entries: &[&Entry],
+	parts: &[Part],
+	answers_path: &Path,
+	out: &mut OutFn,
+) -> Result<bool, String> {
+	let expected = fs::read_to_string(answers_path)
+		.map_err(|e| e.to_string())
+		.and_then(|contents| answers::parse(&contents))
+		.map_err(|e| format!("{}: {}", answers_path.display(), e))?;
+	let expected = expected
+		.into_iter()
+		.filter(|answer| parts.contains(&answer.part))
+		.filter(|answer| {
+			entries
+				.iter()
+				.any(|entry| (entry.year, entry.day) == (answer.year, answer.day))
+		})
+		.collect::<Vec<_>>();
+	if expected.is_empty() {
+		return Err("NO ANSWERS SELECTED".to_string());
+	}
+	let directory = answers_path.parent().unwrap_or_else(|| Path::new(""));
+	Ok(check_all(&expected, directory, out))
+}
+// Checks the examples registered by the selected days.
+pub fn examples_all(entries: &[&Entry], parts: &[Part], out: &mut OutFn) -> Result<bool, String> {
+	let expected = example::expected(entries.iter().copied(), parts);
+	if expected.is_empty() {
+		return Err("NO EXAMPLES SELECTED".to_string());
+	}
+	Ok(check_all(&expected, Path::new(example::DIRECTORY), out))
+}
+fn check_all(expected: &[Expected], directory: &Path, out: &mut OutFn) -> bool {
+	let table = CheckTable::new(expected);
+	out(&table.header());
+	let checks = check_answers(expected, directory, &mut |check| out(&table.row(check)));
+	let failed = checks.iter().filter(|check| !check.passed()).count();
+	out(&format!(
+		"{}\n",
+		report::passed(expected.len() - failed, failed)
+	));
+	failed == 0
+}
+// Compares the fast and reference answers of the selected days on their examples and
+// generated inputs. Every difference is written followed by a line for each day.
+#[cfg(feature = "reference")]
+pub fn differential_all(entries: &[&Entry], parts: &[Part], out: &mut OutFn) -> bool {
+	let mut failed = 0;
+	for entry in entries.iter() {
+		// parts a day lists as too slow to solve by brute force on every input
+		let (parts, skipped): (Vec<Part>, Vec<Part>) = parts
+			.iter()
+			.partition(|part| entry.reference_parts.contains(part));
+		for part in skipped.iter() {
+			out(&report::skipped_part(entry, *part));
+		}
+		let comparisons = differential::compare_day(entry, &parts, &mut |comparison| {
+			if !comparison.passed() {
+				out(&report::difference(comparison));
+			}
+		});
+		let day_failed = comparisons.iter().filter(|c| !c.passed()).count();
+		out(&format!(
+			"{}/{}: {}\n",
+			entry.year,
+			entry.day,
+			report::passed(comparisons.len() - day_failed, day_failed)
+		));
+		failed += day_failed;
+	}
+	failed == 0
+}
+// Checks the selected inputs without solving them, writing every problem with the
+// input followed by a line for each day.
+pub fn lint_all(entries: &[&Entry], source: &InputSource, out: &mut OutFn) -> bool {
+	let mut passed = true;
+	for entry in entries.iter() {
+		let problems = match source.read(entry.year, entry.day) {
+			Ok(input) => (entry.lint)(&input),
+			Err(e) => vec![ParseError::new(entry.year, entry.day, 0, 0, e.to_string())],
+		};
+		for problem in problems.iter() {
+			out(&format!("{}\n", problem));
+		}
+		out(&report::lint_summary(entry, problems.len()));
+		passed &= problems.is_empty();
+	}
+	passed
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::common::generator::Rng;
+	use crate::common::ChallengeT;
+
+	struct Slow;
+	impl ChallengeT for Slow {
+		fn year() -> u16 {
+			2020
+		}
+		fn day() -> u8 {
+			26
+		}
+		fn from_input(_input: &str) -> Result<Self, ParseError> {
+			Ok(Self)
+		}
+		fn part_1(&self) -> Answer {
+			Answer::Integer(1)
+		}
+		fn part_2(&self) -> Answer {
+			thread::sleep(Duration::from_secs(5));
+			Answer::Integer(2)
+		}
+		fn generate(_rng: &mut Rng, _size: usize) -> String {
+			String::new()
+		}
+		#[cfg(feature = "reference")]
+		fn reference_part_1(&self) -> Answer {
+			self.part_1()
+		}
+		#[cfg(feature = "reference")]
+		fn reference_part_2(&self) -> Answer {
+			self.part_2()
+		}
+	}
+	static SLOW: Entry = Entry::new::<Slow>(2020, 26, "Slow");
+
+	#[test]
+	fn timeout_test() {
+		let input = || Ok(String::new());
+		let timeout = Some(Duration::from_millis(50));
+		let records = run_day(&SLOW, &Part::BOTH, input(), timeout);
+		let answers = records
+			.into_iter()
+			.map(|record| record.answer)
+			.collect::<Vec<_>>();
+		assert_eq!(
+			answers,
+			vec![
+				Ok(Answer::Integer(1)),
+				Err(String::from("timed out after 50.000ms"))
+			]
+		);
+		let records = run_day(&SLOW, &[Part::One], input(), timeout);
+		assert_eq!(records[0].answer, Ok(Answer::Integer(1)));
+	}
+
+	#[test]
+	fn examples_all_test() {
+		let entries = [registry::find(2020, 1).unwrap()];
+		let mut output = String::new();
+		let passed = examples_all(&entries, &[Part::One], &mut |text| output.push_str(text));
+		assert_eq!(passed, Ok(true));
+		assert_eq!(
+			output,
+			"input                   day     part expected  actual    result\n\
+			 2020/day_1_example.txt  2020/1  1    514579    514579    pass\n\
+			 1 passed, 0 failed\n"
+		);
+		let passed = examples_all(&[], &Part::BOTH, &mut |_| ());
+		assert_eq!(passed, Err(String::from("NO EXAMPLES SELECTED")));
+	}
+}
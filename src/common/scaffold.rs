@@ -0,0 +1,283 @@
+// File: common/scaffold.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("../day_template.rs");
+const TEMPLATE_FILE: &str = "year_YEAR_NUMBER/day_DAY_NUMBER.rs";
+const YEAR_PLACEHOLDER: &str = "YEAR_NUMBER";
+const DAY_PLACEHOLDER: &str = "DAY_NUMBER";
+const TITLE: &str = "Untitled";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+	Created(PathBuf),
+	Updated(PathBuf),
+}
+
+// Adds a day to the crate in root: a module with a test stub built from the template,
+// its declaration and entry in the year's module and an empty input file.
+// A new year gets its own module which is declared in lib.rs and listed in the registry.
+// Nothing is written if the day already has a module, a declaration or an entry.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<Change>, String> {
+	if year < 2015 {
+		return Err(format!("{} is before the first Advent of Code", year));
+	}
+	if !(1..=25).contains(&day) {
+		return Err(format!("day {} is not between 1 and 25", day));
+	}
+	let year_module = format!("year_{}", year);
+	let module = format!("day_{}", day);
+	let src = root.join("src");
+	let year_path = src.join(&year_module).join("mod.rs");
+	let module_path = src.join(&year_module).join(format!("{}.rs", module));
+	let lib_path = src.join("lib.rs");
+	let registry_path = src.join("common").join("registry.rs");
+	let input_path = root
+		.join("inputs")
+		.join(year.to_string())
+		.join(format!("{}.txt", module));
+
+	let declaration = format!("pub mod {};", module);
+	let entry = format!(
+		"\tEntry::new::<{}::Challenge>(YEAR, {}, \"{}\"),",
+		module, day, TITLE
+	);
+	let mut changes = Vec::new();
+	let mut writes = Vec::new();
+	if year_path.exists() {
+		let days = read(&year_path)?;
+		if module_path.exists()
+			|| days.lines().any(|line| line.trim() == declaration)
+			|| days.contains(&format!("<{}::Challenge>", module))
+		{
+			return Err(format!("{}/{} already exists", year, day));
+		}
+		let days = register(&declare_module(&days, &declaration)?, day, &entry)?;
+		writes.push((year_path.clone(), days));
+		changes.push(Change::Updated(year_path));
+	} else {
+		let days = format!(
+			"{}use crate::common::registry::Entry;\n\n\
+			pub const YEAR: u16 = {};\n\n\
+			{}\n\n\
+			pub static DAYS: &[Entry] = &[\n{}\n];\n",
+			header(&format!("{}/mod.rs", year_module)),
+			year,
+			declaration,
+			entry
+		);
+		let lib = read(&lib_path)?;
+		let registry = read(&registry_path)?;
+		writes.push((year_path.clone(), days));
+		changes.push(Change::Created(year_path));
+		writes.push((
+			lib_path.clone(),
+			declare_module(&lib, &format!("pub mod {};", year_module))?,
+		));
+		changes.push(Change::Updated(lib_path));
+		writes.push((registry_path.clone(), add_year(&registry, year)?));
+		changes.push(Change::Updated(registry_path));
+	}
+	let contents = TEMPLATE
+		.replace(YEAR_PLACEHOLDER, &year.to_string())
+		.replace(DAY_PLACEHOLDER, &day.to_string());
+	writes.insert(0, (module_path.clone(), contents));
+	changes.insert(0, Change::Created(module_path));
+	if !input_path.exists() {
+		writes.push((input_path.clone(), String::new()));
+		changes.push(Change::Created(input_path));
+	}
+
+	for (path, contents) in writes.iter() {
+		write(path, contents)?;
+	}
+	Ok(changes)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+	fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+	if let Some(parent) = path.parent() {
+		fs::create_dir_all(parent).map_err(|e| format!("{}: {}", parent.display(), e))?;
+	}
+	fs::write(path, contents).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+// The license header of the template with the file name of a new file.
+fn header(file: &str) -> String {
+	let end = TEMPLATE.find("*/\n").map_or(0, |i| i + 3);
+	TEMPLATE[..end].replacen(TEMPLATE_FILE, file, 1) + "\n"
+}
+
+// Modules are declared in the order rustfmt sorts them, by name.
+fn declare_module(contents: &str, declaration: &str) -> Result<String, String> {
+	let name = |line: &str| {
+		line.trim_start_matches("pub mod ")
+			.trim_end_matches(';')
+			.to_string()
+	};
+	let prefix = &declaration[..declaration.find('_').unwrap_or(0)];
+	let lines = contents.lines().collect::<Vec<_>>();
+	let modules = lines
+		.iter()
+		.enumerate()
+		.filter(|(_, line)| line.starts_with(prefix))
+		.collect::<Vec<_>>();
+	let last = modules
+		.last()
+		.map(|(i, _)| *i)
+		.ok_or_else(|| format!("no modules like \"{}\" are declared", declaration))?;
+	let index = modules
+		.iter()
+		.find(|(_, line)| name(line) > name(declaration))
+		.map_or(last + 1, |(i, _)| *i);
+	Ok(insert(&lines, index, declaration))
+}
+// Entries are kept in day order.
+fn register(days: &str, day: u8, entry: &str) -> Result<String, String> {
+	let lines = days.lines().collect::<Vec<_>>();
+	let start = lines
+		.iter()
+		.position(|line| line.starts_with("pub static DAYS"))
+		.ok_or("the year does not have a DAYS table")?;
+	let end = start
+		+ lines[start..]
+			.iter()
+			.position(|line| *line == "];")
+			.ok_or("the DAYS table is not closed")?;
+	let index = (start + 1..end)
+		.find(|i| entry_day(lines[*i]).is_some_and(|other| other > day))
+		.unwrap_or(end);
+	Ok(insert(&lines, index, entry))
+}
+fn entry_day(line: &str) -> Option<u8> {
+	line.split_once(">(")?
+		.1
+		.split(',')
+		.nth(1)?
+		.trim()
+		.parse()
+		.ok()
+}
+// Years are listed in order.
+fn add_year(registry: &str, year: u16) -> Result<String, String> {
+	const START: &str = "pub static YEARS: &[&[Entry]] = &[";
+	let start = registry
+		.find(START)
+		.ok_or("registry.rs does not have a YEARS table")?;
+	let end = start
+		+ registry[start..]
+			.find("];")
+			.ok_or("the YEARS table is not closed")?;
+	let mut years = registry[start + START.len()..end]
+		.split(',')
+		.filter_map(|year| {
+			year.trim()
+				.trim_start_matches("crate::year_")
+				.trim_end_matches("::DAYS")
+				.parse::<u16>()
+				.ok()
+		})
+		.collect::<Vec<_>>();
+	years.push(year);
+	years.sort_unstable();
+	let years = years
+		.iter()
+		.map(|year| format!("crate::year_{}::DAYS", year))
+		.collect::<Vec<_>>();
+	let table = if START.len() + years.join(", ").len() + 2 <= 100 {
+		format!("{}{}];", START, years.join(", "))
+	} else {
+		format!("{}\n\t{},\n];", START, years.join(",\n\t"))
+	};
+	Ok(format!(
+		"{}{}{}",
+		&registry[..start],
+		table,
+		&registry[end + 2..]
+	))
+}
+fn insert(lines: &[&str], index: usize, line: &str) -> String {
+	let mut lines = lines.to_vec();
+	lines.insert(index, line);
+	lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::env;
+	#[test]
+	fn new_day_test() {
+		let root = env::temp_dir().join(format!("new_day_test_{}", std::process::id()));
+		let _ = fs::remove_dir_all(&root);
+		let lib = "pub mod common;\n\npub mod year_2020;\n";
+		let registry = "pub static YEARS: &[&[Entry]] = &[crate::year_2020::DAYS];\n";
+		let days = "pub const YEAR: u16 = 2020;\n\npub mod day_1;\npub mod day_3;\n\n\
+			pub static DAYS: &[Entry] = &[\n\
+			\tEntry::new::<day_1::Challenge>(YEAR, 1, \"One\"),\n\
+			\tEntry::new::<day_3::Challenge>(YEAR, 3, \"Three\"),\n\
+			];\n";
+		write(&root.join("src/lib.rs"), lib).unwrap();
+		write(&root.join("src/common/registry.rs"), registry).unwrap();
+		write(&root.join("src/year_2020/mod.rs"), days).unwrap();
+
+		assert!(new_day(&root, 2014, 1).is_err());
+		assert_eq!(new_day(&root, 2020, 2).unwrap().len(), 3);
+		let days = read(&root.join("src/year_2020/mod.rs")).unwrap();
+		assert!(days.contains("pub mod day_1;\npub mod day_2;\npub mod day_3;\n"));
+		assert!(days.contains(
+			"(YEAR, 1, \"One\"),\n\tEntry::new::<day_2::Challenge>(YEAR, 2, \"Untitled\"),\n"
+		));
+		let module = read(&root.join("src/year_2020/day_2.rs")).unwrap();
+		assert!(module.starts_with("// File: year_2020/day_2.rs"));
+		assert!(!module.contains(DAY_PLACEHOLDER) && !module.contains(YEAR_PLACEHOLDER));
+		assert_eq!(read(&root.join("inputs/2020/day_2.txt")).unwrap(), "");
+		assert_eq!(
+			new_day(&root, 2020, 2),
+			Err("2020/2 already exists".to_string())
+		);
+		assert_eq!(
+			new_day(&root, 2020, 3),
+			Err("2020/3 already exists".to_string())
+		);
+
+		assert_eq!(new_day(&root, 2015, 10).unwrap().len(), 5);
+		assert_eq!(
+			read(&root.join("src/lib.rs")).unwrap(),
+			"pub mod common;\n\npub mod year_2015;\npub mod year_2020;\n"
+		);
+		assert_eq!(
+			read(&root.join("src/common/registry.rs")).unwrap(),
+			"pub static YEARS: &[&[Entry]] = &[crate::year_2015::DAYS, crate::year_2020::DAYS];\n"
+		);
+		let days = read(&root.join("src/year_2015/mod.rs")).unwrap();
+		assert!(days.starts_with("// File: year_2015/mod.rs"));
+		assert!(days.ends_with("pub const YEAR: u16 = 2015;\n\npub mod day_10;\n\npub static DAYS: &[Entry] = &[\n\tEntry::new::<day_10::Challenge>(YEAR, 10, \"Untitled\"),\n];\n"));
+		fs::remove_dir_all(&root).unwrap();
+	}
+}
@@ -1,4 +1,4 @@
-// File: day_n.rs
+// File: year_YEAR_NUMBER/day_DAY_NUMBER.rs
 // Author: Jacob Guenther
 // Date: December 2020
 
@@ -22,67 +22,67 @@ COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
 IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
-
-use std::collections::HashMap;
-use std::collections::HashSet;
-
-use super::common::*;
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::{parse_error::ParseError, *};
 
 pub struct Challenge {
-	parsed_input: Vec<&'static str>,
+	lines: Vec<String>,
 }
 impl ChallengeT for Challenge {
-	type Output1 = usize;
-	type Output2 = usize;
+	const EXAMPLES: &'static [Example] = &[];
 
+	fn year() -> u16 {
+		super::YEAR
+	}
 	fn day() -> u8 {
-		n
+		DAY_NUMBER
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let lines = input.lines().map(|line| line.to_string()).collect();
+		Ok(Self { lines })
 	}
-	fn new() -> Self {
-		let parsed_input = include_str!("../inputs/day_n.txt")
-			.lines()
-			.filter_map(|line| {
-				None
-			})
-			.collect();
-		Self {
-			parsed_input: parsed_input,
-		}
+	fn part_1(&self) -> Answer {
+		self.lines.len().into()
 	}
-	fn part_1(&self) -> Self::Output1 {
-		0
+	fn part_2(&self) -> Answer {
+		Answer::NotApplicable
 	}
-	fn part_2(&self) -> Self::Output2 {
-		0
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		(0..size).map(|_| format!("{}\n", rng.word(1..=10))).collect()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		self.lines.len().into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		Answer::NotApplicable
 	}
 }
 
 #[cfg(test)]
 mod tests {
-	use super::Challenge;
+	use super::{Answer, Challenge};
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
 	use test::Bencher;
 
 	#[test]
 	fn part_1_test() {
-		assert_eq!(Challenge::new().part_1(), 0);
+		assert_eq!(Challenge::new().unwrap().part_1(), 0);
 	}
 	#[test]
 	fn part_2_test() {
-		assert_eq!(Challenge::new().part_2(), 0);
+		assert_eq!(Challenge::new().unwrap().part_2(), Answer::NotApplicable);
 	}
 
-    #[bench]
-    fn part_1_bench(b: &mut Bencher) {
-		b.iter(|| Challenge::new().part_1() )
-	}
-    #[bench]
-    fn part_2_bench(b: &mut Bencher) {
-		b.iter(|| Challenge::new().part_2() )
-	}
-    #[bench]
-    fn both(b: &mut Bencher) {
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
 		b.iter(|| {
-			let challenge = Challenge::new();
+			let challenge = Challenge::new().unwrap();
 			challenge.part_1();
 			challenge.part_2();
 		})
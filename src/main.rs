@@ -23,185 +23,338 @@ IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
 
-#![feature(test)]
-extern crate test;
-
 use std::env::args;
-use std::time::Instant;
-
-pub mod common;
-use common::ChallengeT;
+use std::fmt::Display;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-pub mod day_1;
-pub mod day_10;
-pub mod day_11;
-pub mod day_12;
-pub mod day_13;
-pub mod day_14;
-pub mod day_15;
-pub mod day_16;
-pub mod day_17;
-pub mod day_18;
-pub mod day_19;
-pub mod day_2;
-pub mod day_20;
-pub mod day_21;
-pub mod day_22;
-pub mod day_23;
-pub mod day_24;
-pub mod day_25;
-pub mod day_3;
-pub mod day_4;
-pub mod day_5;
-pub mod day_6;
-pub mod day_7;
-pub mod day_8;
-pub mod day_9;
+use advent_of_code::common::bench::Runs;
+use advent_of_code::common::example;
+use advent_of_code::common::history;
+use advent_of_code::common::line_editor;
+use advent_of_code::common::registry::{self, Part, Selector};
+use advent_of_code::common::repl::Session;
+use advent_of_code::common::report::Format;
+use advent_of_code::common::runner::{self, InputSource};
+use advent_of_code::common::scaffold::{self, Change};
 
 pub fn main() {
-	if args().len() == 1 {
-		all();
-	} else {
-		for arg in args() {
-			match arg.as_str() {
-				a if a.starts_with("target") => (),
-				"all" => bench(&all),
-				"threaded" => bench(&all_threaded),
-				"1" => bench(&day_1::Challenge::print_result),
-				"2" => bench(&day_2::Challenge::print_result),
-				"3" => bench(&day_3::Challenge::print_result),
-				"4" => bench(&day_4::Challenge::print_result),
-				"5" => bench(&day_5::Challenge::print_result),
-				"6" => bench(&day_6::Challenge::print_result),
-				"7" => bench(&day_7::Challenge::print_result),
-				"8" => bench(&day_8::Challenge::print_result),
-				"9" => bench(&day_9::Challenge::print_result),
-				"10" => bench(&day_10::Challenge::print_result),
-				"11" => bench(&day_11::Challenge::print_result),
-				"12" => bench(&day_12::Challenge::print_result),
-				"13" => bench(&day_13::Challenge::print_result),
-				"14" => bench(&day_14::Challenge::print_result),
-				"15" => bench(&day_15::Challenge::print_result),
-				"16" => bench(&day_16::Challenge::print_result),
-				"17" => bench(&day_17::Challenge::print_result),
-				"18" => bench(&day_18::Challenge::print_result),
-				"19" => bench(&day_19::Challenge::print_result),
-				"20" => bench(&day_20::Challenge::print_result),
-				"21" => bench(&day_21::Challenge::print_result),
-				"22" => bench(&day_22::Challenge::print_result),
-				"23" => bench(&day_23::Challenge::print_result),
-				"24" => bench(&day_24::Challenge::print_result),
-				"25" => bench(&day_25::Challenge::print_result),
-				_ => println!("ERROR: UNKNOWN ARGUMENT"),
+	let mut source = None;
+	let mut threaded = false;
+	let mut thread_count = None;
+	let mut mode = Mode::Run;
+	let mut runs = Runs {
+		iterations: 10,
+		warmup: 1,
+	};
+	let mut save = false;
+	let mut history_path = PathBuf::from("bench_history.csv");
+	let mut threshold = 10.0;
+	let mut timeout = None;
+	let mut answers_path = PathBuf::from("answers.csv");
+	let mut parts = Part::BOTH.to_vec();
+	let mut format = Format::Text;
+	let mut selectors = Vec::new();
+	let mut arguments = args().skip(1);
+	while let Some(arg) = arguments.next() {
+		match arg.as_str() {
+			"--input" => match arguments.next() {
+				Some(path) => source = Some(InputSource::from_arg(&path)),
+				None => {
+					usage_error("--input EXPECTS A PATH");
+				}
+			},
+			"--part" => match arguments.next().as_deref() {
+				Some("1") => parts = vec![Part::One],
+				Some("2") => parts = vec![Part::Two],
+				_ => {
+					usage_error("--part EXPECTS 1 OR 2");
+				}
+			},
+			"--format" => match arguments.next().as_deref().and_then(Format::from_arg) {
+				Some(f) => format = f,
+				None => {
+					usage_error("--format EXPECTS json, csv OR text");
+				}
+			},
+			"--iterations" | "--warmup" => {
+				match arguments.next().and_then(|n| n.parse::<usize>().ok()) {
+					Some(n) if arg == "--iterations" && n > 0 => runs.iterations = n,
+					Some(n) if arg == "--warmup" => runs.warmup = n,
+					_ => {
+						usage_error(format!("{} EXPECTS A NUMBER", arg));
+					}
+				}
 			}
+			"--history" => match arguments.next() {
+				Some(path) => history_path = PathBuf::from(path),
+				None => {
+					usage_error("--history EXPECTS A PATH");
+				}
+			},
+			"--threshold" => match arguments.next().and_then(|n| n.parse::<f64>().ok()) {
+				Some(percent) if percent >= 0.0 => threshold = percent,
+				_ => {
+					usage_error("--threshold EXPECTS A PERCENTAGE");
+				}
+			},
+			"--timeout" => match arguments.next().and_then(|n| n.parse::<f64>().ok()) {
+				Some(seconds) if seconds > 0.0 && seconds.is_finite() => {
+					timeout = Some(Duration::from_secs_f64(seconds))
+				}
+				_ => {
+					usage_error("--timeout EXPECTS A NUMBER OF SECONDS");
+				}
+			},
+			"--answers" => match arguments.next() {
+				Some(path) => answers_path = PathBuf::from(path),
+				None => {
+					usage_error("--answers EXPECTS A PATH");
+				}
+			},
+			"--save" => save = true,
+			"verify" => mode = Mode::Verify,
+			"examples" => mode = Mode::Examples,
+			"lint" => mode = Mode::Lint,
+			#[cfg(feature = "reference")]
+			"differential" => mode = Mode::Differential,
+			"bench" => mode = Mode::Bench,
+			"compare" => mode = Mode::Compare,
+			"all" => selectors.push(Selector::ALL),
+			"new-day" => {
+				let year = arguments.next().and_then(|year| year.parse::<u16>().ok());
+				let day = arguments.next().and_then(|day| day.parse::<u8>().ok());
+				match (year, day) {
+					(Some(year), Some(day)) => new_day(year, day),
+					_ => usage_error("new-day EXPECTS A YEAR AND A DAY"),
+				}
+				return;
+			}
+			"repl" => {
+				repl(arguments.next());
+				return;
+			}
+			"threaded" => threaded = true,
+			"--threads" => match arguments.next().and_then(|n| n.parse::<usize>().ok()) {
+				Some(n) if n > 0 => {
+					threaded = true;
+					thread_count = Some(n);
+				}
+				_ => {
+					usage_error("--threads EXPECTS A NUMBER");
+				}
+			},
+			_ => match Selector::parse(&arg) {
+				Some(selector) => selectors.push(selector),
+				None => {
+					usage_error(format!("UNKNOWN ARGUMENT {}", arg));
+				}
+			},
 		}
 	}
-}
-fn bench(solution: &dyn Fn()) {
-	let now = Instant::now();
-	solution();
-	let elapsed = now.elapsed();
-	println!(
-		"Estemated Time: {}ms or {}ns",
-		elapsed.as_millis(),
-		elapsed.as_nanos()
-	);
-}
-fn all() {
-	let now = Instant::now();
+	if selectors.is_empty() {
+		selectors.push(Selector::ALL);
+	}
+	let entries = registry::select(&selectors);
+	if entries.is_empty() {
+		usage_error("NO DAYS SELECTED");
+	}
+	if mode != Mode::Run && (threaded || !format.is_text() || timeout.is_some()) {
+		usage_error(format!(
+			"{} CAN NOT BE USED WITH threaded, --format OR --timeout",
+			mode.name()
+		));
+	}
+	if save && !matches!(mode, Mode::Bench | Mode::Compare) {
+		usage_error("--save CAN ONLY BE USED WITH bench OR compare");
+	}
+	if mode == Mode::Verify {
+		if source.is_some() {
+			usage_error("verify READS THE INPUTS NAMED IN THE ANSWERS FILE, NOT --input");
+		}
+		exit_unless(runner::verify_all(
+			&entries,
+			&parts,
+			&answers_path,
+			&mut print,
+		));
+		return;
+	}
+	if mode == Mode::Examples {
+		if source.is_some() {
+			usage_error(format!(
+				"examples READS THE INPUTS IN {}, NOT --input",
+				example::DIRECTORY
+			));
+		}
+		exit_unless(runner::examples_all(&entries, &parts, &mut print));
+		return;
+	}
+	#[cfg(feature = "reference")]
+	if mode == Mode::Differential {
+		if source.is_some() {
+			usage_error("differential SOLVES THE EXAMPLES AND GENERATED INPUTS, NOT --input");
+		}
+		if !runner::differential_all(&entries, &parts, &mut print) {
+			process::exit(1);
+		}
+		return;
+	}
+	let source = source.unwrap_or_default();
+	if source.is_single_day() && (entries.len() != 1 || threaded) {
+		usage_error("AN INPUT FILE CAN ONLY BE USED WITH A SINGLE DAY");
+	}
+	if mode == Mode::Lint {
+		if !runner::lint_all(&entries, &source, &mut print) {
+			process::exit(1);
+		}
+		return;
+	}
 
-	day_1::Challenge::print_result();
-	day_2::Challenge::print_result();
-	day_3::Challenge::print_result();
-	day_4::Challenge::print_result();
-	day_5::Challenge::print_result();
-	day_6::Challenge::print_result();
-	day_7::Challenge::print_result();
-	day_8::Challenge::print_result();
-	day_9::Challenge::print_result();
-	day_10::Challenge::print_result();
-	day_11::Challenge::print_result();
-	day_12::Challenge::print_result();
-	day_13::Challenge::print_result();
-	day_14::Challenge::print_result();
-	day_15::Challenge::print_result();
-	day_16::Challenge::print_result();
-	day_17::Challenge::print_result();
-	day_18::Challenge::print_result();
-	day_19::Challenge::print_result();
-	day_20::Challenge::print_result();
-	day_21::Challenge::print_result();
-	day_22::Challenge::print_result();
-	day_23::Challenge::print_result();
-	day_24::Challenge::print_result();
-	day_25::Challenge::print_result();
+	let stats = match mode {
+		Mode::Run | Mode::Verify | Mode::Examples | Mode::Lint => None,
+		#[cfg(feature = "reference")]
+		Mode::Differential => None,
+		Mode::Bench => Some(runner::bench_all(
+			&entries, &parts, &source, runs, &mut print,
+		)),
+		Mode::Compare => {
+			let history = match history::load(&history_path) {
+				Ok(history) => history,
+				Err(e) => {
+					eprintln!("ERROR: {}", e);
+					process::exit(1);
+				}
+			};
+			let (stats, passed) = runner::compare_all(
+				&entries,
+				&parts,
+				&source,
+				runs,
+				&history::baseline(&history),
+				threshold,
+				&mut print,
+			);
+			if !passed {
+				process::exit(1);
+			}
+			Some(stats)
+		}
+	};
+	if let Some(stats) = stats {
+		if save {
+			let run = SystemTime::now()
+				.duration_since(UNIX_EPOCH)
+				.map_or(0, |time| time.as_secs());
+			if let Err(e) = history::append(&history_path, run, &stats) {
+				eprintln!("ERROR: {}: {}", history_path.display(), e);
+				process::exit(1);
+			}
+			println!("Saved to {}", history_path.display());
+		}
+		return;
+	}
 
-	let elapsed = now.elapsed();
-	println!(
-		"Estemated Time: {}ms or {}us",
-		elapsed.as_millis(),
-		elapsed.as_micros()
-	);
+	if threaded {
+		let threads = thread_count
+			.unwrap_or_else(|| thread::available_parallelism().map_or(1, NonZeroUsize::get));
+		runner::write_threaded(
+			&entries, &parts, &source, format, threads, timeout, &mut print,
+		);
+	} else {
+		runner::write_all(&entries, &parts, &source, format, timeout, &mut print);
+	}
 }
-fn all_threaded() {
-	let result_fns = [
-		day_1::Challenge::result_string,
-		day_2::Challenge::result_string,
-		day_3::Challenge::result_string,
-		day_4::Challenge::result_string,
-		day_5::Challenge::result_string,
-		day_6::Challenge::result_string,
-		day_7::Challenge::result_string,
-		day_8::Challenge::result_string,
-		day_9::Challenge::result_string,
-		day_10::Challenge::result_string,
-		day_11::Challenge::result_string,
-		day_12::Challenge::result_string,
-		day_13::Challenge::result_string,
-		day_14::Challenge::result_string,
-		day_15::Challenge::result_string,
-		day_16::Challenge::result_string,
-		day_17::Challenge::result_string,
-		day_18::Challenge::result_string,
-		day_19::Challenge::result_string,
-		day_20::Challenge::result_string,
-		day_21::Challenge::result_string,
-		day_22::Challenge::result_string,
-		day_23::Challenge::result_string,
-		day_24::Challenge::result_string,
-		day_25::Challenge::result_string,
-	];
-	let do_part = |result_fns: &[fn() -> String]| {
-		let now = Instant::now();
 
-		let mut res = String::new();
-		for f in result_fns.iter() {
-			res.push_str(&f());
-			res.push('\n');
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Mode {
+	Run,
+	Bench,
+	Compare,
+	Verify,
+	Examples,
+	Lint,
+	#[cfg(feature = "reference")]
+	Differential,
+}
+impl Mode {
+	fn name(&self) -> &'static str {
+		match self {
+			Mode::Run => "run",
+			Mode::Bench => "bench",
+			Mode::Compare => "compare",
+			Mode::Verify => "verify",
+			Mode::Examples => "examples",
+			Mode::Lint => "lint",
+			#[cfg(feature = "reference")]
+			Mode::Differential => "differential",
 		}
-		let elapsed = now.elapsed();
-		(
-			format!(
-				"Estemated Time: {}ms or {}us",
-				elapsed.as_millis(),
-				elapsed.as_micros()
-			),
-			res,
-		)
-	};
-	let a_handle = std::thread::spawn(move || do_part(&result_fns[0..15]));
-	let b_handle = std::thread::spawn(move || do_part(&result_fns[15..21]));
-	let c_handle = std::thread::spawn(move || do_part(&result_fns[21..22]));
-	let d_handle = std::thread::spawn(move || do_part(&result_fns[22..25]));
-	let (time_a, res_a) = a_handle.join().unwrap();
-	let (time_b, res_b) = b_handle.join().unwrap();
-	let (time_c, res_c) = c_handle.join().unwrap();
-	let (time_d, res_d) = d_handle.join().unwrap();
-	println!("{}{}{}{}", res_a, res_b, res_c, res_d);
-	if true {
-		println!(
-			"th1: {}\nth2: {}\nth3: {}\nth4: {}",
-			time_a, time_b, time_c, time_d
+	}
+}
+
+// Arguments that can not be used are reported on stderr and the exit code is an error
+// so scripts don't mistake them for a run that passed.
+fn usage_error(message: impl Display) -> ! {
+	eprintln!("ERROR: {}", message);
+	process::exit(1);
+}
+fn exit_unless(passed: Result<bool, String>) {
+	match passed {
+		Ok(true) => (),
+		Ok(false) => process::exit(1),
+		Err(e) => {
+			eprintln!("ERROR: {}", e);
+			process::exit(1);
+		}
+	}
+}
+fn print(text: &str) {
+	print!("{}", text);
+}
+// The day is added to the source tree this binary was built from.
+fn new_day(year: u16, day: u8) {
+	match scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), year, day) {
+		Ok(changes) => {
+			for change in changes {
+				match change {
+					Change::Created(path) => println!("Created {}", path.display()),
+					Change::Updated(path) => println!("Updated {}", path.display()),
+				}
+			}
+		}
+		Err(e) => {
+			eprintln!("ERROR: {}", e);
+			process::exit(1);
+		}
+	}
+}
+// Reads commands until quit or the end of the input, starting on the day if one is given.
+fn repl(day: Option<String>) {
+	let mut session = Session::new();
+	if let Some(day) = day {
+		print!(
+			"{}",
+			session.execute(&format!("day {}", day)).unwrap_or_default()
 		);
 	}
+	loop {
+		let line = line_editor::read_line(&session.prompt(), &session.history, &|line| {
+			session.complete(line)
+		});
+		let line = match line {
+			Ok(Some(line)) => line,
+			Ok(None) => return,
+			Err(e) => {
+				eprintln!("ERROR: {}", e);
+				process::exit(1);
+			}
+		};
+		match session.execute(&line) {
+			Some(output) => print!("{}", output),
+			None => return,
+		}
+	}
 }
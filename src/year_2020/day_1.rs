@@ -0,0 +1,215 @@
+// File: year_2020/day_1.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::lint;
+use crate::common::parse_error::{parse_number, ParseError};
+use crate::common::ChallengeT;
+use std::collections::HashSet;
+
+pub struct Challenge {
+	report: Vec<u32>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] =
+		&[Example::new("example", Some("514579"), Some("241861950"))];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		1
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let mut report = input
+			.lines()
+			.map(|line| parse_number(Self::year(), Self::day(), input, line))
+			.collect::<Result<Vec<u32>, _>>()?;
+		report.sort_unstable();
+		Ok(Self { report })
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, _>(
+			input,
+			input
+				.lines()
+				.map(|line| parse_number::<u32>(Self::year(), Self::day(), input, line)),
+		)
+	}
+	fn part_1(&self) -> Answer {
+		let mut lower_i = 0;
+		let mut upper_i = self.report.len().saturating_sub(1);
+		while lower_i < upper_i {
+			let lower = self.report[lower_i];
+			let upper = self.report[upper_i];
+			let sum = upper + lower;
+			match sum {
+				2020 => return (upper * lower).into(),
+				s if s < 2020 => lower_i += 1,
+				_ => upper_i -= 1,
+			}
+		}
+		Answer::NotApplicable
+	}
+	fn part_2(&self) -> Answer {
+		let len = self.report.len();
+		for i in 0..len.saturating_sub(2) {
+			let mut lower_i = i + 1;
+			let mut upper_i = len - 1;
+			while lower_i < upper_i {
+				let first = self.report[i];
+				let second = self.report[lower_i];
+				let third = self.report[upper_i];
+				let sum = first + second + third;
+				match sum {
+					2020 => return (first * second * third).into(),
+					s if s < 2020 => lower_i += 1,
+					_ => upper_i -= 1,
+				}
+			}
+		}
+		Answer::NotApplicable
+	}
+	// At most one pair and one triple sum to 2020 so every solution finds the same ones.
+	// Now and then the pair or the triple is left out.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let mut report = Vec::<u32>::new();
+		let mut pair_sums = HashSet::<u32>::new();
+		let add = |report: &mut Vec<u32>, pair_sums: &mut HashSet<u32>, entry: u32| {
+			pair_sums.extend(report.iter().map(|other| other + entry));
+			report.push(entry);
+		};
+		let triple = loop {
+			let first = rng.range(1..=1000) as u32;
+			let second = rng.range(1..=(2018 - first as usize)) as u32;
+			let third = 2020 - first - second;
+			if first != second && second != third && first != third {
+				break [first, second, third];
+			}
+		};
+		if !rng.chance(10) {
+			for entry in triple.iter() {
+				add(&mut report, &mut pair_sums, *entry);
+			}
+		}
+		let pair_sums_before = pair_sums.clone();
+		let half = loop {
+			let half = rng.range(1..=2019) as u32;
+			let unique = |entry: u32| {
+				entry != 1010
+					&& !report.contains(&entry)
+					&& !report.contains(&(2020 - entry))
+					&& !pair_sums_before.contains(&(2020 - entry))
+			};
+			if unique(half) && unique(2020 - half) {
+				break half;
+			}
+		};
+		if !rng.chance(10) {
+			add(&mut report, &mut pair_sums, half);
+			add(&mut report, &mut pair_sums, 2020 - half);
+		}
+		// each new entry can not make another pair or triple
+		for _ in 0..(size.max(5) * 10) {
+			if report.len() >= size.max(5) {
+				break;
+			}
+			let entry = rng.range(1..=2019) as u32;
+			if !report.contains(&entry)
+				&& !report.contains(&(2020 - entry))
+				&& !pair_sums.contains(&(2020 - entry))
+			{
+				add(&mut report, &mut pair_sums, entry);
+			}
+		}
+		rng.shuffle(&mut report);
+		report.iter().map(|entry| format!("{}\n", entry)).collect()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		let report = &self.report;
+		for i in 0..report.len() {
+			for j in (i + 1)..report.len() {
+				if report[i] + report[j] == 2020 {
+					return (report[i] as u64 * report[j] as u64).into();
+				}
+			}
+		}
+		Answer::NotApplicable
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		let report = &self.report;
+		for i in 0..report.len() {
+			for j in (i + 1)..report.len() {
+				for k in (j + 1)..report.len() {
+					if report[i] + report[j] + report[k] == 2020 {
+						return (report[i] as u64 * report[j] as u64 * report[k] as u64).into();
+					}
+				}
+			}
+		}
+		Answer::NotApplicable
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 545379);
+	}
+	#[test]
+	fn part_2() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 257778836);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn part_1_bench(b: &mut Bencher) {
+		b.iter(|| Challenge::new().unwrap().part_1())
+	}
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn part_2_bench(b: &mut Bencher) {
+		b.iter(|| Challenge::new().unwrap().part_2())
+	}
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
@@ -0,0 +1,212 @@
+// File: year_2020/day_10.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::lint;
+use crate::common::parse_error::{parse_number, ParseError};
+use crate::common::ChallengeT;
+use std::collections::HashMap;
+
+pub struct Challenge {
+	adapters: Vec<usize>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[
+		Example::new("example", Some("35"), Some("8")),
+		Example::new("larger", Some("220"), Some("19208")),
+	];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		10
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let mut adapters = input
+			.lines()
+			.map(|line| parse_number(Self::year(), Self::day(), input, line))
+			.collect::<Result<Vec<usize>, _>>()?;
+		if adapters.is_empty() {
+			return Err(ParseError::new(
+				Self::year(),
+				Self::day(),
+				1,
+				1,
+				"expected at least one number",
+			));
+		}
+		adapters.sort_unstable();
+
+		Ok(Self { adapters })
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, _>(
+			input,
+			input
+				.lines()
+				.map(|line| parse_number::<usize>(Self::year(), Self::day(), input, line)),
+		)
+	}
+	fn part_1(&self) -> Answer {
+		// from the outlet at 0 jolts through the adapters, the device is always 3 jolts higher
+		let joltages = std::iter::once(&0).chain(self.adapters.iter());
+		let [difference_1, difference_3] =
+			joltages
+				.zip(&self.adapters)
+				.fold([0, 1], |mut acc, (current, next)| {
+					if current + 1 == *next {
+						acc[0] += 1;
+					} else if current + 3 == *next {
+						acc[1] += 1;
+					}
+					acc
+				});
+		(difference_1 * difference_3).into()
+	}
+	fn part_2(&self) -> Answer {
+		let mut adapters = self.adapters.clone();
+		adapters.insert(0, 0);
+		find_permutations(&adapters, &mut HashMap::new()).into()
+	}
+	// Adapters are 1 to 3 jolts apart. Once the number of arrangements gets large
+	// the gaps are 3 jolts so it stays far from overflowing.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let mut adapters = Vec::new();
+		// the joltages within 3 of the last one and how many arrangements reach them
+		let mut recent = vec![(0, 1u64)];
+		for _ in 0..size.max(1) {
+			let (last, last_count) = *recent.last().unwrap();
+			let gap = match rng.below(10) {
+				_ if last_count > 1 << 40 => 3,
+				0..=5 => 1,
+				6 => 2,
+				_ => 3,
+			};
+			let joltage = last + gap;
+			recent.retain(|(previous, _)| joltage - previous <= 3);
+			let count = recent.iter().map(|(_, count)| count).sum();
+			recent.push((joltage, count));
+			adapters.push(joltage);
+		}
+		rng.shuffle(&mut adapters);
+		adapters
+			.iter()
+			.map(|adapter| format!("{}\n", adapter))
+			.collect()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		// the outlet, every adapter in order and then the device
+		let mut joltages = vec![0];
+		joltages.extend(self.adapters.iter().copied());
+		joltages.push(joltages.last().unwrap() + 3);
+		let differences = joltages
+			.windows(2)
+			.map(|pair| pair[1] - pair[0])
+			.collect::<Vec<_>>();
+		let count = |difference| differences.iter().filter(|d| **d == difference).count();
+		(count(1) * count(3)).into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		// the ways to reach each joltage are the ways to reach every joltage
+		// from 1 to 3 below it
+		let mut joltages = vec![0];
+		joltages.extend(self.adapters.iter().copied());
+		let mut ways = vec![0u64; joltages.len()];
+		ways[0] = 1;
+		for i in 1..joltages.len() {
+			ways[i] = (0..i)
+				.filter(|j| joltages[i] - joltages[*j] <= 3)
+				.map(|j| ways[j])
+				.sum();
+		}
+		ways[ways.len() - 1].into()
+	}
+}
+
+fn find_permutations(adapters: &[usize], cache: &mut HashMap<usize, usize>) -> usize {
+	match adapters.split_first().unwrap() {
+		(_, []) => 1,
+		(first, rest) => rest
+			.iter()
+			.take_while(|a| *a - first <= 3)
+			.enumerate()
+			.map(|(i, val)| match cache.get(val) {
+				Some(v) => *v,
+				None => {
+					let partial_count = find_permutations(&rest[i..], cache);
+					cache.insert(*val, partial_count);
+					partial_count
+				}
+			})
+			.sum(),
+	}
+}
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 1998);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 347250213298688usize);
+	}
+	#[test]
+	fn outlet_test() {
+		// 2 from the outlet, then 1, then 3, then 3 to the device
+		let challenge = Challenge::from_input("2\n3\n6\n").unwrap();
+		assert_eq!(challenge.part_1(), 2);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn part_1_bench(b: &mut Bencher) {
+		b.iter(|| Challenge::new().unwrap().part_1())
+	}
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn part_2_bench(b: &mut Bencher) {
+		b.iter(|| Challenge::new().unwrap().part_2())
+	}
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
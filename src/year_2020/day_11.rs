@@ -0,0 +1,272 @@
+// File: year_2020/day_11.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::grid::{Grid, GridT, DIAGONAL_ADJACENT};
+use crate::common::lint;
+use crate::common::parse_error::ParseError;
+use crate::common::ChallengeT;
+use std::collections::HashSet;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+enum Tile {
+	Floor,
+	Empty,
+	Filled,
+}
+type Map = Grid<Tile>;
+type AdjacencyFn = dyn Fn(&Map, &Tile, &mut Map, usize, usize);
+
+pub struct Challenge {
+	parsed_input: Map,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[Example::new("example", Some("37"), Some("26"))];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		11
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		Ok(Self {
+			parsed_input: Grid::parse(Self::year(), Self::day(), input, parse_tile)?,
+		})
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		let problems = Grid::problems(Self::year(), Self::day(), input, parse_tile);
+		lint::each::<Self, _>(input, problems.into_iter().map(Err::<(), _>))
+	}
+	fn part_1(&self) -> Answer {
+		let mut previous = Grid::new(0, 0, &Tile::Floor);
+		let mut current = self.parsed_input.clone();
+		while previous != current {
+			previous = current;
+			current = step_map(&previous, &p1_adjacency);
+		}
+		count_seats(&current).into()
+	}
+	fn part_2(&self) -> Answer {
+		let mut previous = Grid::new(0, 0, &Tile::Floor);
+		let mut current = self.parsed_input.clone();
+		while previous != current {
+			previous = current;
+			current = step_map(&previous, &p2_adjacency);
+		}
+		count_seats(&current).into()
+	}
+	// Some seat layouts flip between two states forever so a layout is only kept
+	// when the seats settle under both rules. Larger layouts rarely settle.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let height = size.clamp(1, 30);
+		let width = rng.range(1..=height.max(10));
+		let map = loop {
+			let mut map = Grid::new(width, height, &Tile::Empty);
+			for tile in map.data.iter_mut() {
+				if rng.chance(15) {
+					*tile = Tile::Floor;
+				}
+			}
+			if settles(&map, &p1_adjacency) && settles(&map, &p2_adjacency) {
+				break map;
+			}
+		};
+		map.render(|tile| match tile {
+			Tile::Floor => '.',
+			_ => 'L',
+		})
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		reference_settle(&self.parsed_input, 1, 4).into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		reference_settle(&self.parsed_input, usize::MAX, 5).into()
+	}
+}
+
+// Changes every seat at once until nothing changes. Each seat looks up to reach
+// tiles in every direction for the first seat and is left when crowded seats are filled.
+#[cfg(feature = "reference")]
+fn reference_settle(map: &Map, reach: usize, crowded: usize) -> usize {
+	let first_seat_filled = |map: &Map, x: usize, y: usize, (dx, dy): (isize, isize)| {
+		let (mut x, mut y) = (x as isize, y as isize);
+		for _ in 0..reach {
+			x += dx;
+			y += dy;
+			match map.get_i(x, y) {
+				Some(Tile::Floor) => (),
+				Some(tile) => return *tile == Tile::Filled,
+				None => return false,
+			}
+		}
+		false
+	};
+	let mut current = map.clone();
+	loop {
+		let mut next = current.clone();
+		for y in 0..current.size.y {
+			for x in 0..current.size.x {
+				let filled = DIAGONAL_ADJACENT
+					.iter()
+					.filter(|step| first_seat_filled(&current, x, y, **step))
+					.count();
+				next[(x, y)] = match (current[(x, y)], filled) {
+					(Tile::Empty, 0) => Tile::Filled,
+					(Tile::Filled, filled) if filled >= crowded => Tile::Empty,
+					(tile, _) => tile,
+				};
+			}
+		}
+		if next == current {
+			return count_seats(&current);
+		}
+		current = next;
+	}
+}
+fn settles(map: &Map, adjacency_fn: &AdjacencyFn) -> bool {
+	let mut seen = HashSet::new();
+	let mut current = map.clone();
+	while seen.insert(current.clone()) {
+		let next = step_map(&current, adjacency_fn);
+		if next == current {
+			return true;
+		}
+		current = next;
+	}
+	false
+}
+fn step_map(current: &Map, adjacency_fn: &AdjacencyFn) -> Map {
+	let mut new = current.clone();
+	for y in 0..current.size.y {
+		for x in 0..current.size.x {
+			let tile = &current[(x, y)];
+			if *tile == Tile::Floor {
+				continue;
+			}
+			adjacency_fn(current, tile, &mut new, x, y);
+		}
+	}
+	new
+}
+fn p1_adjacency(current: &Map, current_tile: &Tile, new: &mut Map, x: usize, y: usize) {
+	let adjacent_filled = current
+		.diagonal_adjacent_cells(x, y)
+		.filter(|(_, tile)| **tile == Tile::Filled)
+		.count();
+	if adjacent_filled == 0 && *current_tile == Tile::Empty {
+		new[(x, y)] = Tile::Filled;
+	} else if adjacent_filled > 3 && *current_tile == Tile::Filled {
+		new[(x, y)] = Tile::Empty;
+	}
+}
+fn p2_adjacency(current: &Map, current_tile: &Tile, new: &mut Map, x: usize, y: usize) {
+	let mut visible_filled_seats = 0;
+	for (dx, dy) in DIAGONAL_ADJACENT.iter() {
+		let (mut pos_x, mut pos_y) = (x as isize, y as isize);
+		loop {
+			pos_x += dx;
+			pos_y += dy;
+			match current.get_i(pos_x, pos_y) {
+				Some(Tile::Filled) => {
+					visible_filled_seats += 1;
+					break;
+				}
+				Some(Tile::Empty) | None => break,
+				Some(Tile::Floor) => (),
+			}
+		}
+	}
+	if visible_filled_seats == 0 && *current_tile == Tile::Empty {
+		new[(x, y)] = Tile::Filled;
+	} else if visible_filled_seats > 4 && *current_tile == Tile::Filled {
+		new[(x, y)] = Tile::Empty;
+	}
+}
+fn count_seats(current: &Map) -> usize {
+	current
+		.data
+		.iter()
+		.filter(|tile| **tile == Tile::Filled)
+		.count()
+}
+fn parse_tile(byte: u8) -> Result<Tile, ()> {
+	match byte {
+		b'.' => Ok(Tile::Floor),
+		b'L' => Ok(Tile::Empty),
+		b'#' => Ok(Tile::Filled),
+		_ => Err(()),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 2386);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 2091);
+	}
+	#[test]
+	fn lint_test() {
+		let problems = Challenge::lint("L.L#\nLxL\n#.#.\nLLLLy");
+		let positions = problems
+			.iter()
+			.map(|problem| (problem.line, problem.column))
+			.collect::<Vec<_>>();
+		assert_eq!(positions, [(2, 1), (2, 2), (4, 1), (4, 5)]);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn part_1_bench(b: &mut Bencher) {
+		b.iter(|| Challenge::new().unwrap().part_1())
+	}
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn part_2_bench(b: &mut Bencher) {
+		b.iter(|| Challenge::new().unwrap().part_2())
+	}
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
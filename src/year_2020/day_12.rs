@@ -0,0 +1,318 @@
+// File: year_2020/day_12.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::lint;
+use crate::common::parse_error::{parse_number, ParseError};
+use crate::common::vec2::Vec2;
+use crate::common::ChallengeT;
+
+pub struct Challenge {
+	directions: Vec<Direction>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[Example::new("example", Some("25"), Some("286"))];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		12
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let directions = input
+			.lines()
+			.map(|line| parse_direction(input, line))
+			.collect::<Result<Vec<_>, _>>()?;
+		Ok(Self { directions })
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, _>(
+			input,
+			input.lines().map(|line| parse_direction(input, line)),
+		)
+	}
+	fn part_1(&self) -> Answer {
+		let res = self.navigate();
+		((res.distance.x.abs() + res.distance.y.abs()) as usize).into()
+	}
+	fn part_2(&self) -> Answer {
+		let res = self.navigate();
+		((res.actual_distance.x.abs() + res.actual_distance.y.abs()) as usize).into()
+	}
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		(0..size.max(1))
+			.map(
+				|_| match *rng.choose(&['N', 'S', 'E', 'W', 'L', 'R', 'F']) {
+					turn @ ('L' | 'R') => format!("{}{}\n", turn, rng.range(1..=3) * 90),
+					action => format!("{}{}\n", action, rng.range(1..=100)),
+				},
+			)
+			.collect()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		let (mut x, mut y) = (0, 0);
+		// facing east
+		let (mut dx, mut dy) = (1, 0);
+		for direction in self.directions.iter() {
+			match *direction {
+				Direction::North(distance) => y += distance,
+				Direction::South(distance) => y -= distance,
+				Direction::East(distance) => x += distance,
+				Direction::West(distance) => x -= distance,
+				Direction::Left(angle) => {
+					for _ in 0..angle / 90 {
+						(dx, dy) = (-dy, dx);
+					}
+				}
+				Direction::Right(angle) => {
+					for _ in 0..angle / 90 {
+						(dx, dy) = (dy, -dx);
+					}
+				}
+				Direction::Forward(distance) => {
+					x += dx * distance;
+					y += dy * distance;
+				}
+			}
+		}
+		((x.abs() + y.abs()) as i64).into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		let (mut x, mut y) = (0, 0);
+		let (mut waypoint_x, mut waypoint_y) = (10, 1);
+		for direction in self.directions.iter() {
+			match *direction {
+				Direction::North(distance) => waypoint_y += distance,
+				Direction::South(distance) => waypoint_y -= distance,
+				Direction::East(distance) => waypoint_x += distance,
+				Direction::West(distance) => waypoint_x -= distance,
+				Direction::Left(angle) => {
+					for _ in 0..angle / 90 {
+						(waypoint_x, waypoint_y) = (-waypoint_y, waypoint_x);
+					}
+				}
+				Direction::Right(angle) => {
+					for _ in 0..angle / 90 {
+						(waypoint_x, waypoint_y) = (waypoint_y, -waypoint_x);
+					}
+				}
+				Direction::Forward(times) => {
+					x += waypoint_x * times;
+					y += waypoint_y * times;
+				}
+			}
+		}
+		((x.abs() + y.abs()) as i64).into()
+	}
+}
+impl Challenge {
+	fn navigate(&self) -> Payload {
+		self.directions
+			.iter()
+			.fold(Payload::new(), |mut acc, &dir| {
+				match dir {
+					Direction::Forward(dist) => {
+						match acc.facing {
+							Direction::North(_) => acc.distance.y += dist,
+							Direction::South(_) => acc.distance.y -= dist,
+							Direction::East(_) => acc.distance.x += dist,
+							Direction::West(_) => acc.distance.x -= dist,
+							_ => (),
+						}
+
+						acc.actual_distance.x += dist * acc.waypoint.x;
+						acc.actual_distance.y += dist * acc.waypoint.y;
+					}
+
+					Direction::Left(angle) => {
+						acc.facing = acc.facing.rotate_left(angle);
+						acc.waypoint = rotate_waypoint_left(&acc.waypoint, angle);
+					}
+					Direction::Right(angle) => {
+						acc.facing = acc.facing.rotate_right(angle);
+						acc.waypoint = rotate_waypoint_right(&acc.waypoint, angle);
+					}
+
+					Direction::North(dist) => {
+						acc.distance.y += dist;
+						acc.waypoint.y += dist;
+					}
+					Direction::South(dist) => {
+						acc.distance.y -= dist;
+						acc.waypoint.y -= dist;
+					}
+					Direction::East(dist) => {
+						acc.distance.x += dist;
+						acc.waypoint.x += dist;
+					}
+					Direction::West(dist) => {
+						acc.distance.x -= dist;
+						acc.waypoint.x -= dist;
+					}
+				}
+				acc
+			})
+	}
+}
+#[derive(Copy, Clone, Debug)]
+struct Payload {
+	facing: Direction,
+	distance: Vec2<isize>,
+
+	waypoint: Vec2<isize>,
+	actual_distance: Vec2<isize>,
+}
+impl Payload {
+	fn new() -> Self {
+		Self {
+			facing: Direction::East(0),
+			distance: Vec2::new(0, 0),
+
+			waypoint: Vec2::new(10, 1),
+			actual_distance: Vec2::new(0, 0),
+		}
+	}
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Direction {
+	North(isize),
+	South(isize),
+	East(isize),
+	West(isize),
+	Left(isize),
+	Right(isize),
+	Forward(isize),
+}
+impl Direction {
+	fn rotate_left(&self, amount: isize) -> Self {
+		let mut new = *self;
+		for _ in 0..amount / 90 {
+			new = match new {
+				Direction::North(d) => Direction::West(d),
+				Direction::West(d) => Direction::South(d),
+				Direction::South(d) => Direction::East(d),
+				Direction::East(d) => Direction::North(d),
+				_ => panic!("Cannot rotate a rotation"),
+			}
+		}
+		new
+	}
+	fn rotate_right(&self, amount: isize) -> Self {
+		let mut new = *self;
+		for _ in 0..amount / 90 {
+			new = match new {
+				Direction::North(d) => Direction::East(d),
+				Direction::East(d) => Direction::South(d),
+				Direction::South(d) => Direction::West(d),
+				Direction::West(d) => Direction::North(d),
+				_ => panic!("Cannot rotate a rotation"),
+			}
+		}
+		new
+	}
+}
+fn parse_direction(input: &str, line: &str) -> Result<Direction, ParseError> {
+	let mut chars = line.chars();
+	let action = chars.next();
+	let distance = parse_number(Challenge::year(), Challenge::day(), input, chars.as_str())?;
+	match (action, distance) {
+		(Some('L' | 'R'), angle) if !matches!(angle, 90 | 180 | 270) => Err(ParseError::at(
+			Challenge::year(),
+			Challenge::day(),
+			input,
+			chars.as_str(),
+			format!("{} is not a supported angle", angle),
+		)),
+		(Some('N'), _) => Ok(Direction::North(distance)),
+		(Some('S'), _) => Ok(Direction::South(distance)),
+		(Some('E'), _) => Ok(Direction::East(distance)),
+		(Some('W'), _) => Ok(Direction::West(distance)),
+		(Some('L'), _) => Ok(Direction::Left(distance)),
+		(Some('R'), _) => Ok(Direction::Right(distance)),
+		(Some('F'), _) => Ok(Direction::Forward(distance)),
+		(action, _) => Err(ParseError::at(
+			Challenge::year(),
+			Challenge::day(),
+			input,
+			line,
+			format!("unknown action {:?}", action.unwrap_or(' ')),
+		)),
+	}
+}
+fn rotate_waypoint_left(waypoint: &Vec2<isize>, angle: isize) -> Vec2<isize> {
+	match angle {
+		90 => Vec2::new(-waypoint.y, waypoint.x),
+		180 => Vec2::new(-waypoint.x, -waypoint.y),
+		270 => Vec2::new(waypoint.y, -waypoint.x),
+		_ => panic!("Rotation not supported"),
+	}
+}
+fn rotate_waypoint_right(waypoint: &Vec2<isize>, angle: isize) -> Vec2<isize> {
+	match angle {
+		270 => Vec2::new(-waypoint.y, waypoint.x),
+		180 => Vec2::new(-waypoint.x, -waypoint.y),
+		90 => Vec2::new(waypoint.y, -waypoint.x),
+		_ => panic!("Rotation not supported"),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 1186);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 47806);
+	}
+	#[test]
+	fn parse_error_test() {
+		let error = Challenge::from_input("F10\nN3\nX7\nR90\nF11")
+			.err()
+			.unwrap();
+		assert_eq!((error.day, error.line, error.column), (12, 3, 1));
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
@@ -0,0 +1,239 @@
+// File: year_2020/day_13.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+#[cfg(feature = "reference")]
+use crate::common::gcd;
+use crate::common::generator::Rng;
+use crate::common::lint;
+use crate::common::{
+	chinese_remainder_theorem::chinese_remainder_theorem,
+	parse_error::{parse_number, ParseError},
+	ChallengeT,
+};
+
+pub struct Challenge {
+	earliest_departure: usize,
+	// (offset, bus id)
+	buses: Vec<(usize, usize)>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[
+		Example::new("example", Some("295"), Some("1068781")),
+		Example::new("a", None, Some("3417")),
+		Example::new("b", None, Some("754018")),
+		Example::new("c", None, Some("779210")),
+		Example::new("d", None, Some("1261476")),
+		Example::new("e", None, Some("1202161486")),
+	];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		13
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let mut lines = input.lines();
+		let (departure_line, bus_line) = match (lines.next(), lines.next()) {
+			(Some(departure_line), Some(bus_line)) => (departure_line, bus_line),
+			_ => {
+				return Err(ParseError::new(
+					Self::year(),
+					Self::day(),
+					2,
+					1,
+					"expected an earliest departure and a line of bus ids",
+				))
+			}
+		};
+
+		let earliest_departure =
+			parse_number::<usize>(Self::year(), Self::day(), input, departure_line)?;
+		let buses = bus_line
+			.split(',')
+			.enumerate()
+			.filter(|(_, b)| *b != "x")
+			.map(|(i, b)| Ok((i, parse_bus(input, b)?)))
+			.collect::<Result<Vec<(usize, usize)>, _>>()?;
+		if buses.is_empty() {
+			return Err(ParseError::at(
+				Self::year(),
+				Self::day(),
+				input,
+				bus_line,
+				"expected a bus id",
+			));
+		}
+
+		Ok(Self {
+			earliest_departure,
+			buses,
+		})
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		let mut lines = input.lines();
+		let departure = lines
+			.next()
+			.map(|line| parse_number::<usize>(Self::year(), Self::day(), input, line).map(drop));
+		let buses = lines
+			.next()
+			.into_iter()
+			.flat_map(|line| line.split(','))
+			.filter(|b| *b != "x")
+			.map(|b| parse_bus(input, b).map(drop));
+		lint::each::<Self, _>(input, departure.into_iter().chain(buses))
+	}
+	fn part_1(&self) -> Answer {
+		let mut lowest = usize::MAX;
+		let mut best_bus = 0;
+		self.buses.iter().for_each(|(_, b)| {
+			let mut dep = *b;
+			dep = (self.earliest_departure as f64 / dep as f64).ceil() as usize * dep;
+			if dep < lowest {
+				lowest = dep;
+				best_bus = *b;
+			}
+		});
+
+		(best_bus * (lowest - self.earliest_departure)).into()
+	}
+	fn part_2(&self) -> Answer {
+		let mut residues = Vec::new();
+		let mut modulii = Vec::new();
+		self.buses[1..].iter().for_each(|(i, b)| {
+			let mut j = -(*i as i128);
+			while j < 0 {
+				j += *b as i128;
+			}
+			residues.push(j as u128);
+			modulii.push(*b as u128);
+		});
+		let (mut part_2_result, modulus) = chinese_remainder_theorem(&residues, &modulii);
+		let (_, bus_id) = self.buses[0];
+		let first_bus = bus_id as u128;
+		loop {
+			if part_2_result % first_bus == 0 {
+				break;
+			}
+			part_2_result += modulus;
+		}
+		part_2_result.into()
+	}
+	// Bus ids are distinct primes so part 2 has an answer, the first bus leaves at offset 0
+	// and no two buses leave first at the same time.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let mut primes = (7..1000)
+			.filter(|n| (2..*n).take_while(|d| d * d <= *n).all(|d| n % d != 0))
+			.collect::<Vec<usize>>();
+		loop {
+			rng.shuffle(&mut primes);
+			let buses = &primes[..size.clamp(2, 9)];
+			let earliest_departure = rng.range(1..=1_000_000);
+			let mut waits = buses
+				.iter()
+				.map(|bus| (bus - earliest_departure % bus) % bus)
+				.collect::<Vec<_>>();
+			waits.sort_unstable();
+			if waits.len() > 1 && waits[0] == waits[1] {
+				continue;
+			}
+			let mut schedule = Vec::new();
+			for bus in buses.iter() {
+				if !schedule.is_empty() {
+					schedule.extend((0..rng.below(12)).map(|_| String::from("x")));
+				}
+				schedule.push(bus.to_string());
+			}
+			return format!("{}\n{}\n", earliest_departure, schedule.join(","));
+		}
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		// waits a minute at a time until a bus leaves
+		let mut time = self.earliest_departure;
+		loop {
+			if let Some((_, bus)) = self.buses.iter().find(|(_, bus)| time.is_multiple_of(*bus)) {
+				return (bus * (time - self.earliest_departure)).into();
+			}
+			time += 1;
+		}
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		// once the time suits some buses it only moves in steps that keep them suited
+		let mut time = 0u128;
+		let mut step = 1u128;
+		for (offset, bus) in self.buses.iter() {
+			let (offset, bus) = (*offset as u128, *bus as u128);
+			while !(time + offset).is_multiple_of(bus) {
+				time += step;
+			}
+			// the least common multiple without overflowing on step * bus
+			step = step / gcd((step % bus) as usize, bus as usize).unwrap() as u128 * bus;
+		}
+		time.into()
+	}
+}
+fn parse_bus(input: &str, id: &str) -> Result<usize, ParseError> {
+	match parse_number(Challenge::year(), Challenge::day(), input, id)? {
+		0 => Err(ParseError::at(
+			Challenge::year(),
+			Challenge::day(),
+			input,
+			id,
+			"bus ids can not be 0",
+		)),
+		id => Ok(id),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 2406);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 225850756401039u128);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
@@ -0,0 +1,308 @@
+// File: year_2020/day_14.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::lint;
+use crate::common::parse_error::{parse_number, ParseError};
+use crate::common::ChallengeT;
+
+use std::collections::HashMap;
+
+pub struct Challenge {
+	instructions: Vec<Instruction>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[
+		Example::new("example", Some("165"), None),
+		Example::new("floating", None, Some("208")),
+	];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		14
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let instructions = input
+			.lines()
+			.map(|line| parse_line(input, line))
+			.collect::<Result<Vec<_>, _>>()?;
+		Ok(Self { instructions })
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, _>(input, input.lines().map(|line| parse_line(input, line)))
+	}
+	fn part_1(&self) -> Answer {
+		let mut mask_0s = 0;
+		let mut mask_1s = 0;
+		self.instructions
+			.iter()
+			.filter_map(|instruction| match instruction {
+				Instruction::Mask(mask) => {
+					mask_0s = 0;
+					mask_1s = 0;
+					for c in mask.chars() {
+						mask_0s <<= 1;
+						mask_1s <<= 1;
+						match c {
+							'0' => (),
+							'1' => {
+								mask_1s += 1;
+								mask_0s += 1;
+							}
+							_ => mask_0s += 1,
+						}
+					}
+					None
+				}
+				Instruction::Write { address, value } => {
+					let result = (value | mask_1s) & mask_0s;
+					Some((*address, result))
+				}
+			})
+			.collect::<HashMap<u64, u64>>()
+			.values()
+			.sum::<u64>()
+			.into()
+	}
+	fn part_2(&self) -> Answer {
+		let mut mask_1s = 0;
+		let mut mask_floating = 0;
+		let mut floating_bits_count = 0;
+		let mut memory = HashMap::<u64, u64>::new();
+		// Note: '0' is 48 and '1' is 49
+		self.instructions
+			.iter()
+			.for_each(|instruction| match instruction {
+				Instruction::Mask(mask) => {
+					mask_1s = 0;
+					mask_floating = 0;
+					floating_bits_count = 0;
+					mask.as_bytes().iter().for_each(|b| {
+						mask_1s <<= 1;
+						mask_floating <<= 1;
+						match b {
+							48 => (),
+							49 => mask_1s += 1,
+							_ => {
+								mask_floating += 1;
+								floating_bits_count += 1;
+							}
+						}
+					});
+				}
+				Instruction::Write { address, value } => {
+					let base_address = address | mask_1s;
+
+					let mut addresses = Vec::with_capacity(1 << floating_bits_count);
+					build_addresses(mask_floating, base_address, 0, &mut addresses);
+					addresses.iter().for_each(|address| {
+						memory.insert(*address, *value);
+					});
+				}
+			});
+		memory.values().sum::<u64>().into()
+	}
+	// Masks have at most 9 floating bits so part 2 writes at most 512 addresses at once.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let mut program = String::new();
+		let mut writes = 0;
+		while writes < size.max(1) {
+			let floating = rng.range(0..=9);
+			let mut mask = (0..36)
+				.map(|i| match (i < floating, rng.chance(50)) {
+					(true, _) => 'X',
+					(false, true) => '1',
+					(false, false) => '0',
+				})
+				.collect::<Vec<_>>();
+			rng.shuffle(&mut mask);
+			program.push_str(&format!("mask = {}\n", mask.iter().collect::<String>()));
+			for _ in 0..rng.range(1..=5) {
+				program.push_str(&format!(
+					"mem[{}] = {}\n",
+					rng.below(1 << 16),
+					rng.below(1 << 36)
+				));
+				writes += 1;
+			}
+		}
+		program
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		let mut memory = HashMap::new();
+		let mut mask = "";
+		for instruction in self.instructions.iter() {
+			match instruction {
+				Instruction::Mask(new_mask) => mask = new_mask,
+				Instruction::Write { address, value } => {
+					let bits = format!("{:036b}", value)
+						.chars()
+						.zip(mask.chars())
+						.map(|(bit, mask_bit)| if mask_bit == 'X' { bit } else { mask_bit })
+						.collect::<String>();
+					memory.insert(*address, u64::from_str_radix(&bits, 2).unwrap());
+				}
+			}
+		}
+		memory.values().sum::<u64>().into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		let mut memory = HashMap::new();
+		let mut mask = "";
+		for instruction in self.instructions.iter() {
+			match instruction {
+				Instruction::Mask(new_mask) => mask = new_mask,
+				Instruction::Write { address, value } => {
+					// every address so far with each floating bit as both 0 and 1
+					let mut addresses = vec![String::new()];
+					for (bit, mask_bit) in format!("{:036b}", address).chars().zip(mask.chars()) {
+						let bits = match mask_bit {
+							'0' => vec![bit],
+							'1' => vec!['1'],
+							_ => vec!['0', '1'],
+						};
+						addresses = addresses
+							.iter()
+							.flat_map(|address| {
+								bits.iter().map(move |bit| format!("{}{}", address, bit))
+							})
+							.collect();
+					}
+					for address in addresses.iter() {
+						memory.insert(u64::from_str_radix(address, 2).unwrap(), *value);
+					}
+				}
+			}
+		}
+		memory.values().sum::<u64>().into()
+	}
+}
+enum Instruction {
+	Mask(String),
+	Write { address: u64, value: u64 },
+}
+fn parse_line(input: &str, line: &str) -> Result<Instruction, ParseError> {
+	let year = Challenge::year();
+	let day = Challenge::day();
+	if let Some(mask) = line.strip_prefix("mask = ") {
+		if let Some((i, c)) = mask
+			.char_indices()
+			.find(|(_, c)| !matches!(c, '0' | '1' | 'X'))
+		{
+			return Err(ParseError::at(
+				year,
+				day,
+				input,
+				&mask[i..],
+				format!("unexpected '{}' in mask", c),
+			));
+		}
+		if mask.len() != 36 {
+			return Err(ParseError::at(
+				year,
+				day,
+				input,
+				mask,
+				format!("expected 36 bits but found {}", mask.len()),
+			));
+		}
+		Ok(Instruction::Mask(mask.to_string()))
+	} else if let Some(rest) = line.strip_prefix("mem[") {
+		let (address, value) = rest.split_once("] = ").ok_or_else(|| {
+			ParseError::at(
+				year,
+				day,
+				input,
+				line,
+				"expected \"mem[<address>] = <value>\"",
+			)
+		})?;
+		Ok(Instruction::Write {
+			address: parse_number(year, day, input, address)?,
+			value: parse_number(year, day, input, value)?,
+		})
+	} else {
+		Err(ParseError::at(
+			year,
+			day,
+			input,
+			line,
+			"expected \"mask = <mask>\" or \"mem[<address>] = <value>\"",
+		))
+	}
+}
+fn build_addresses(mask: u64, address: u64, current: u64, addresses: &mut Vec<u64>) {
+	if current == 64 {
+		addresses.push(address);
+		return;
+	}
+	let mask_bit = (mask >> current) & 1;
+	let address_bit = (address >> current) & 1;
+	match (mask_bit, address_bit) {
+		(1, 0) => {
+			let a = address + (1 << current);
+			build_addresses(mask, a, current + 1, addresses);
+		}
+		(1, 1) => {
+			let a = address - (1 << current);
+			build_addresses(mask, a, current + 1, addresses);
+		}
+		_ => (),
+	};
+	build_addresses(mask, address, current + 1, addresses)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 15_018_100_062_885u64);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 5_724_245_857_696u64);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
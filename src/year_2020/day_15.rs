@@ -0,0 +1,215 @@
+// File: year_2020/day_15.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::lint;
+use crate::common::parse_error::{parse_number, ParseError};
+use crate::common::query::Query;
+use crate::common::registry::Part;
+use crate::common::ChallengeT;
+#[cfg(feature = "reference")]
+use std::collections::HashMap;
+
+pub struct Challenge {
+	starting_numbers: Vec<i32>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[
+		Example::new("example", Some("436"), Some("175594")),
+		Example::new("a", Some("1"), None),
+		Example::new("b", Some("10"), None),
+		Example::new("c", Some("27"), None),
+		Example::new("d", Some("78"), None),
+		Example::new("e", Some("438"), None),
+		Example::new("f", Some("1836"), None),
+	];
+	const QUERIES: &'static [Query] = &[Query::new(
+		"play",
+		"<starting numbers> <turns>",
+		"the number spoken on the last turn",
+		play,
+	)];
+	// part 2 takes too long to run on every generated input
+	const GENERATED_PARTS: &'static [Part] = &[Part::One];
+	#[cfg(feature = "reference")]
+	const REFERENCE_PARTS: &'static [Part] = &[Part::One];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		15
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let starting_numbers = input
+			.trim()
+			.split(',')
+			.map(|n| parse_starting_number(input, n))
+			.collect::<Result<Vec<_>, _>>()?;
+		Ok(Self { starting_numbers })
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, _>(
+			input,
+			input
+				.trim()
+				.split(',')
+				.map(|n| parse_starting_number(input, n)),
+		)
+	}
+	fn part_1(&self) -> Answer {
+		self.play(2020).into()
+	}
+	fn part_2(&self) -> Answer {
+		self.play(30_000_000).into()
+	}
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let mut numbers = (0..20).collect::<Vec<_>>();
+		rng.shuffle(&mut numbers);
+		let numbers = numbers[..size.clamp(1, 7)]
+			.iter()
+			.map(|n| n.to_string())
+			.collect::<Vec<_>>();
+		numbers.join(",") + "\n"
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		reference_play(&self.starting_numbers, 2020).into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		reference_play(&self.starting_numbers, 30_000_000).into()
+	}
+}
+
+// Remembers the last turn each number was spoken on, the turn before the current one
+// is only recorded once the next number is known.
+#[cfg(feature = "reference")]
+fn reference_play(starting_numbers: &[i32], turns: usize) -> i32 {
+	let mut spoken_on = HashMap::new();
+	let mut spoken = starting_numbers[0];
+	for turn in 1..turns {
+		let next = match starting_numbers.get(turn) {
+			Some(number) => *number,
+			None => spoken_on
+				.get(&spoken)
+				.map_or(0, |previous| (turn - previous) as i32),
+		};
+		spoken_on.insert(spoken, turn);
+		spoken = next;
+	}
+	spoken
+}
+impl Challenge {
+	// The number spoken on the last of the given turns.
+	fn play(&self, turns: usize) -> i32 {
+		if turns <= self.starting_numbers.len() {
+			return self.starting_numbers[turns - 1];
+		}
+		// index is number, value is the turn it was spoken on
+		let largest = *self.starting_numbers.iter().max().unwrap() as usize;
+		let mut turn_spoken_on = vec![-1; turns.max(largest + 1)];
+		for (i, num) in self.starting_numbers.iter().enumerate() {
+			turn_spoken_on[*num as usize] = i as i32;
+		}
+
+		let mut previous_spoken = *self.starting_numbers.last().unwrap() as usize;
+		let start = self.starting_numbers.len() as i32;
+		for current_turn in start..turns as i32 {
+			let spoken = match turn_spoken_on[previous_spoken] {
+				-1 => 0,
+				last_spoken_on => current_turn - last_spoken_on - 1,
+			};
+			turn_spoken_on[previous_spoken] = current_turn - 1;
+			previous_spoken = spoken as usize;
+		}
+		previous_spoken as i32
+	}
+}
+// A game with other starting numbers or another number of turns, like "0,3,6 2020".
+fn play(arguments: &[&str]) -> Result<Answer, ParseError> {
+	let year = Challenge::year();
+	let day = Challenge::day();
+	let (starting_numbers, turns) = match arguments {
+		[starting_numbers, turns] => (*starting_numbers, *turns),
+		_ => {
+			return Err(ParseError::new(
+				year,
+				day,
+				0,
+				0,
+				"expected starting numbers and a number of turns",
+			))
+		}
+	};
+	let challenge = Challenge::from_input(starting_numbers)?;
+	match parse_number(year, day, turns, turns)? {
+		turns @ 1..=100_000_000 => Ok(challenge.play(turns).into()),
+		_ => Err(ParseError::new(
+			year,
+			day,
+			0,
+			0,
+			"turns must be between 1 and 100000000",
+		)),
+	}
+}
+fn parse_starting_number(input: &str, n: &str) -> Result<i32, ParseError> {
+	match parse_number::<i32>(Challenge::year(), Challenge::day(), input, n)? {
+		number @ 0..=29_999_999 => Ok(number),
+		_ => Err(ParseError::at(
+			Challenge::year(),
+			Challenge::day(),
+			input,
+			n,
+			"starting numbers must be between 0 and 30000000",
+		)),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	// use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 706);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 19331);
+	}
+	#[test]
+	fn query_test() {
+		assert_eq!(super::play(&["0,3,6", "2020"]).unwrap(), 436);
+		assert_eq!(super::play(&["3,1,2", "2020"]).unwrap(), 1836);
+		assert!(super::play(&["0,3,6", "0"]).is_err());
+		assert!(super::play(&["0,3,6"]).is_err());
+	}
+}
@@ -0,0 +1,445 @@
+// File: year_2020/day_16.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::{
+	parse_error::{parse_number, ParseError},
+	vec2::Vec2,
+	*,
+};
+
+pub struct Challenge {
+	notes: Notes,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[Example::new("example", Some("71"), None)];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		16
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let year = Self::year();
+		let day = Self::day();
+		let mut split_input = input.split("\n\n");
+		let mut next_section = |name: &str| {
+			split_input.next().ok_or_else(|| {
+				ParseError::at(
+					year,
+					day,
+					input,
+					&input[input.len()..],
+					format!("expected {}", name),
+				)
+			})
+		};
+
+		let fields = next_section("fields")?
+			.lines()
+			.map(|line| parse_field(input, line))
+			.collect::<Result<Vec<Field>, _>>()?;
+
+		let my_ticket = parse_ticket(
+			input,
+			ticket_lines(input, next_section("your ticket")?)?,
+			fields.len(),
+		)?;
+
+		let nearby_tickets = ticket_lines(input, next_section("nearby tickets")?)?
+			.split_whitespace()
+			.map(|line| parse_ticket(input, line, fields.len()))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(Self {
+			notes: Notes {
+				fields,
+				my_ticket,
+				nearby_tickets,
+			},
+		})
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		let mut sections = input.split("\n\n");
+		let field_lines = sections.next().unwrap_or_default().lines();
+		let field_count = field_lines.clone().count();
+		let fields = field_lines.map(|line| parse_field(input, line).map(drop));
+		// your ticket and the nearby tickets
+		let tickets = sections
+			.take(2)
+			.flat_map(|section| match ticket_lines(input, section) {
+				Ok(tickets) => tickets
+					.split_whitespace()
+					.map(|line| parse_ticket(input, line, field_count).map(drop))
+					.collect(),
+				Err(e) => vec![Err(e)],
+			});
+		lint::each::<Self, _>(input, fields.chain(tickets))
+	}
+	fn part_1(&self) -> Answer {
+		self.notes
+			.nearby_tickets
+			.iter()
+			.filter_map(|ticket| self.notes.invalid_value(ticket))
+			.sum::<usize>()
+			.into()
+	}
+	fn part_2(&self) -> Answer {
+		let filtered_tickets = self
+			.notes
+			.nearby_tickets
+			.iter()
+			.filter(|ticket| self.notes.invalid_value(ticket).is_none())
+			.collect::<Vec<_>>();
+		let height = filtered_tickets.len();
+		let width = self.notes.fields.len();
+
+		let mut columns = vec![vec![0; height]; width];
+		for (y, column) in columns.iter_mut().enumerate() {
+			for (x, item) in column.iter_mut().enumerate() {
+				*item = filtered_tickets[x][y];
+			}
+		}
+
+		let matches = columns
+			.iter()
+			.map(|column| {
+				self.notes
+					.fields
+					.iter()
+					.filter_map(|(field_name, range1, range2)| {
+						for value in column.iter() {
+							if !bound_by(*value, range1, range2) {
+								return None;
+							}
+						}
+						Some(field_name.as_str())
+					})
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+
+		let col_names = find_order(&matches, 0, &Vec::new()).unwrap();
+		col_names
+			.iter()
+			.zip(self.notes.my_ticket.iter())
+			.filter(|(col_name, _)| col_name.starts_with("de"))
+			.fold(1, |product, (_, &ticket_val)| product * ticket_val)
+			.into()
+	}
+	// Field i of n accepts a-b or c-d where a shrinks and d grows with i, so some value in the
+	// column of field i is only accepted by fields i and above. That leaves one way to match
+	// fields to columns. Invalid tickets have one value no field accepts.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		const OTHER_FIELDS: [&str; 14] = [
+			"arrival location",
+			"arrival station",
+			"arrival platform",
+			"arrival track",
+			"class",
+			"duration",
+			"price",
+			"route",
+			"row",
+			"seat",
+			"train",
+			"type",
+			"wagon",
+			"zone",
+		];
+		let mut names = ["location", "station", "platform", "track", "date", "time"]
+			.iter()
+			.map(|name| format!("departure {}", name))
+			.chain(OTHER_FIELDS.iter().map(|name| name.to_string()))
+			.collect::<Vec<_>>();
+		rng.shuffle(&mut names[6..]);
+		names.truncate(rng.range(6..=20));
+		rng.shuffle(&mut names);
+		let n = names.len();
+
+		let gap_start = rng.range(300..=600);
+		let gap_end = gap_start + rng.range(10..=30);
+		let ranges = (0..n)
+			.map(|i| [(25 + 2 * (n - 1 - i), gap_start), (gap_end, 950 + i)])
+			.collect::<Vec<_>>();
+		// column j holds field order[j]
+		let mut order = (0..n).collect::<Vec<_>>();
+		rng.shuffle(&mut order);
+		let valid_ticket = |rng: &mut Rng| {
+			order
+				.iter()
+				.map(|&i| {
+					let range = rng.choose(&ranges[i]);
+					rng.range(range.0..=range.1)
+				})
+				.collect::<Vec<_>>()
+		};
+		let my_ticket = valid_ticket(rng);
+		let mut tickets = (0..size.max(1))
+			.map(|_| valid_ticket(rng))
+			.collect::<Vec<_>>();
+		for (j, &i) in order.iter().enumerate() {
+			let ticket = rng.below(tickets.len());
+			tickets[ticket][j] = if rng.chance(50) {
+				ranges[i][0].0
+			} else {
+				ranges[i][1].1
+			};
+		}
+		for _ in 0..(tickets.len() / 4) {
+			let mut ticket = valid_ticket(rng);
+			ticket[rng.below(n)] = match rng.below(3) {
+				0 => rng.range(0..=24),
+				1 => rng.range((gap_start + 1)..=(gap_end - 1)),
+				_ => rng.range((950 + n)..=999),
+			};
+			tickets.insert(rng.below(tickets.len() + 1), ticket);
+		}
+
+		let join = |ticket: &Vec<usize>| {
+			let values = ticket.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+			values.join(",") + "\n"
+		};
+		let fields = names
+			.iter()
+			.zip(ranges.iter())
+			.map(|(name, [(a, b), (c, d)])| format!("{}: {}-{} or {}-{}\n", name, a, b, c, d))
+			.collect::<String>();
+		format!(
+			"{}\nyour ticket:\n{}\nnearby tickets:\n{}",
+			fields,
+			join(&my_ticket),
+			tickets.iter().map(join).collect::<String>()
+		)
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		self.notes
+			.nearby_tickets
+			.iter()
+			.flatten()
+			.filter(|value| !self.reference_accepted(**value))
+			.sum::<usize>()
+			.into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		let valid = self
+			.notes
+			.nearby_tickets
+			.iter()
+			.filter(|ticket| ticket.iter().all(|value| self.reference_accepted(*value)))
+			.collect::<Vec<_>>();
+		// the fields each column could be, then fields are matched to a column with only
+		// one possible field until every field is matched
+		let mut possible = (0..self.notes.fields.len())
+			.map(|column| {
+				self.notes
+					.fields
+					.iter()
+					.filter(|(_, range_1, range_2)| {
+						valid
+							.iter()
+							.all(|ticket| bound_by(ticket[column], range_1, range_2))
+					})
+					.map(|(name, _, _)| name.as_str())
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+		let mut matched = vec![None; possible.len()];
+		while let Some(column) = (0..possible.len()).find(|column| possible[*column].len() == 1) {
+			let name = possible[column][0];
+			matched[column] = Some(name);
+			for names in possible.iter_mut() {
+				names.retain(|other| *other != name);
+			}
+		}
+		let mut product = 1;
+		for (name, value) in matched.iter().zip(self.notes.my_ticket.iter()) {
+			match name {
+				Some(name) if name.starts_with("departure") => product *= value,
+				Some(_) => (),
+				None => return Answer::NotApplicable,
+			}
+		}
+		product.into()
+	}
+}
+
+#[cfg(feature = "reference")]
+impl Challenge {
+	fn reference_accepted(&self, value: usize) -> bool {
+		self.notes
+			.fields
+			.iter()
+			.any(|(_, range_1, range_2)| bound_by(value, range_1, range_2))
+	}
+}
+
+type FieldName<'a> = &'a str;
+type Field = (String, Vec2<usize>, Vec2<usize>);
+type Ticket = Vec<usize>;
+struct Notes {
+	fields: Vec<Field>,
+	my_ticket: Ticket,
+	// Every nearby ticket, including the invalid ones.
+	nearby_tickets: Vec<Ticket>,
+}
+impl Notes {
+	// The first value on the ticket no field accepts.
+	fn invalid_value(&self, ticket: &[usize]) -> Option<usize> {
+		ticket.iter().copied().find(|value| {
+			!self
+				.fields
+				.iter()
+				.any(|(_, range1, range2)| bound_by(*value, range1, range2))
+		})
+	}
+}
+fn parse_field(input: &str, line: &str) -> Result<Field, ParseError> {
+	let (name, ranges) = line
+		.split_once(": ")
+		.and_then(|(name, ranges)| Some((name, ranges.split_once(" or ")?)))
+		.ok_or_else(|| {
+			ParseError::at(
+				Challenge::year(),
+				Challenge::day(),
+				input,
+				line,
+				"expected \"<field>: <a>-<b> or <c>-<d>\"",
+			)
+		})?;
+	Ok((
+		name.to_owned(),
+		parse_range(input, ranges.0)?,
+		parse_range(input, ranges.1)?,
+	))
+}
+fn parse_range(input: &str, range: &str) -> Result<Vec2<usize>, ParseError> {
+	let year = Challenge::year();
+	let day = Challenge::day();
+	let (start, end) = range
+		.split_once('-')
+		.ok_or_else(|| ParseError::at(year, day, input, range, "expected \"<a>-<b>\""))?;
+	Ok(Vec2::new(
+		parse_number(year, day, input, start)?,
+		parse_number(year, day, input, end)?,
+	))
+}
+// Skips the "your ticket:" or "nearby tickets:" header of a section.
+fn ticket_lines<'a>(input: &str, section: &'a str) -> Result<&'a str, ParseError> {
+	section
+		.split_once(":\n")
+		.map(|(_, tickets)| tickets)
+		.ok_or_else(|| {
+			ParseError::at(
+				Challenge::year(),
+				Challenge::day(),
+				input,
+				section,
+				"expected a ticket header",
+			)
+		})
+}
+fn parse_ticket(input: &str, line: &str, field_count: usize) -> Result<Ticket, ParseError> {
+	let ticket = line
+		.split(',')
+		.map(|s| parse_number(Challenge::year(), Challenge::day(), input, s))
+		.collect::<Result<Ticket, _>>()?;
+	if ticket.len() != field_count {
+		return Err(ParseError::at(
+			Challenge::year(),
+			Challenge::day(),
+			input,
+			line,
+			format!("expected {} values but found {}", field_count, ticket.len()),
+		));
+	}
+	Ok(ticket)
+}
+fn bound_by(value: usize, range1: &Vec2<usize>, range2: &Vec2<usize>) -> bool {
+	(value >= range1.x && value <= range1.y) || (value >= range2.x && value <= range2.y)
+}
+fn find_order<'a>(
+	matches: &[Vec<FieldName<'a>>],
+	current: usize,
+	partial: &[FieldName<'a>],
+) -> Option<Vec<FieldName<'a>>> {
+	if current == matches.len() {
+		return Some(partial.to_owned());
+	}
+	let mut param = partial.to_owned();
+	for option in matches[current].iter() {
+		if partial.contains(option) {
+			continue;
+		}
+		param.push(option);
+		if let Some(result) = find_order(matches, current + 1, &param) {
+			return Some(result);
+		}
+		param.pop();
+	}
+	None
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 26941);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 634796407951usize);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn part_1_bench(b: &mut Bencher) {
+		b.iter(|| Challenge::new().unwrap().part_1())
+	}
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn part_2_bench(b: &mut Bencher) {
+		b.iter(|| Challenge::new().unwrap().part_2())
+	}
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
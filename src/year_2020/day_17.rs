@@ -0,0 +1,272 @@
+// File: year_2020/day_17.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+#[cfg(feature = "reference")]
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::registry::Part;
+use crate::common::{grid::Grid, parse_error::ParseError, vec3::Vec3, vec4::Vec4, *};
+
+pub struct Challenge {
+	// x and y of the active cubes in the initial slice
+	initial: Vec<(i16, i16)>,
+	size: usize,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[Example::new("example", Some("112"), Some("848"))];
+	// part 2 takes too long to run on every generated input, with its reference solution
+	// even in a release build
+	const GENERATED_PARTS: &'static [Part] = &[Part::One];
+	#[cfg(feature = "reference")]
+	const REFERENCE_PARTS: &'static [Part] = &[Part::One];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		17
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let slice = Grid::parse(Self::year(), Self::day(), input, parse_cube)?;
+		let initial = (0..slice.size.y)
+			.flat_map(|y| (0..slice.size.x).map(move |x| (x, y)))
+			.filter(|(x, y)| slice[(*x, *y)])
+			.map(|(x, y)| (x as i16, y as i16))
+			.collect();
+		Ok(Self {
+			initial,
+			size: slice.size.x.max(slice.size.y),
+		})
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		let problems = Grid::problems(Self::year(), Self::day(), input, parse_cube);
+		lint::each::<Self, _>(input, problems.into_iter().map(Err::<(), _>))
+	}
+	fn part_1(&self) -> Answer {
+		let mut active = self
+			.initial
+			.iter()
+			.map(|(x, y)| Vec3::<i16>::new(*x, *y, 0))
+			.collect::<HashSet<_>>();
+		let mut next_active = HashSet::new();
+
+		let mut part_1_result = 0usize;
+		for step in 0..6 {
+			next_active.clear();
+			let min = -(step + 1);
+			let max = self.size as i16 + step + 1;
+			for x in min..max {
+				for y in min..max {
+					for z in 0..(step + 2) {
+						let coord = Vec3::new(x, y, z);
+						let active_count = count_active_adjacent(&active, &coord);
+						match (active.contains(&coord), active_count) {
+							(true, 2) | (_, 3) => {
+								if step == 5 {
+									if z == 0 {
+										part_1_result += 1;
+									} else {
+										part_1_result += 2;
+									}
+								}
+								next_active.insert(coord);
+								if z != 0 {
+									next_active.insert(Vec3::new(x, y, -z));
+								}
+							}
+							_ => (),
+						}
+					}
+				}
+			}
+			std::mem::swap(&mut active, &mut next_active);
+		}
+		part_1_result.into()
+	}
+	fn part_2(&self) -> Answer {
+		let mut active_2 = self
+			.initial
+			.iter()
+			.map(|(x, y)| Vec4::<i16>::new(*x, *y, 0, 0))
+			.collect::<HashSet<_>>();
+		let mut next_active_2 = HashSet::new();
+
+		let mut part_2_result = 0usize;
+		for step in 0..6 {
+			next_active_2.clear();
+			let min = -(step + 1);
+			let max = self.size as i16 + step + 1;
+			for x in min..max {
+				for y in min..max {
+					for z in 0..(step + 2) {
+						for w in 0..(step + 2) {
+							let coord = Vec4::new(x, y, z, w);
+							let active_count = count_active_adjacent(&active_2, &coord);
+							match (active_2.contains(&coord), active_count) {
+								(true, 2) | (_, 3) => {
+									if step == 5 {
+										if z == 0 && w == 0 {
+											part_2_result += 1;
+										} else if z != 0 && w != 0 {
+											part_2_result += 4;
+										} else {
+											part_2_result += 2;
+										}
+									} else {
+										next_active_2.insert(coord);
+										if w != 0 {
+											next_active_2.insert(Vec4::new(x, y, z, -w));
+										}
+										if z != 0 {
+											next_active_2.insert(Vec4::new(x, y, -z, w));
+										}
+										if z != 0 && w != 0 {
+											next_active_2.insert(Vec4::new(x, y, -z, -w));
+										}
+									}
+								}
+								_ => (),
+							}
+						}
+					}
+				}
+			}
+			std::mem::swap(&mut active_2, &mut next_active_2);
+		}
+		part_2_result.into()
+	}
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let side = size.clamp(1, 8);
+		(0..side)
+			.map(|_| {
+				let mut row = (0..side)
+					.map(|_| if rng.chance(45) { '#' } else { '.' })
+					.collect::<String>();
+				row.push('\n');
+				row
+			})
+			.collect()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		reference_boot(&self.initial, 3).into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		reference_boot(&self.initial, 4).into()
+	}
+}
+
+// Counts the active neighbours of every cube next to an active cube each cycle, using
+// the first 3 or all 4 coordinates.
+#[cfg(feature = "reference")]
+fn reference_boot(initial: &[(i16, i16)], dimensions: u32) -> usize {
+	let offsets = (0..3i32.pow(dimensions))
+		.map(|i| {
+			let mut offset = [0; 4];
+			for (d, o) in offset.iter_mut().enumerate().take(dimensions as usize) {
+				*o = i / 3i32.pow(d as u32) % 3 - 1;
+			}
+			offset
+		})
+		.filter(|offset| *offset != [0; 4])
+		.collect::<Vec<_>>();
+	let mut active = initial
+		.iter()
+		.map(|(x, y)| [*x as i32, *y as i32, 0, 0])
+		.collect::<HashSet<_>>();
+	for _ in 0..6 {
+		let mut neighbours = HashMap::new();
+		for cube in active.iter() {
+			for offset in offsets.iter() {
+				let mut neighbour = *cube;
+				for (n, o) in neighbour.iter_mut().zip(offset.iter()) {
+					*n += o;
+				}
+				*neighbours.entry(neighbour).or_insert(0) += 1;
+			}
+		}
+		active = neighbours
+			.into_iter()
+			.filter(|(cube, count)| *count == 3 || (*count == 2 && active.contains(cube)))
+			.map(|(cube, _)| cube)
+			.collect();
+	}
+	active.len()
+}
+fn count_active_adjacent<T>(active: &HashSet<T>, coord: &T) -> usize
+where
+	T: NeighborsT + std::cmp::Eq + std::hash::Hash + Copy,
+{
+	let mut count = 0;
+	for neighbor in coord.neighbors().iter() {
+		if active.contains(neighbor) {
+			count += 1;
+			if count > 3 {
+				break;
+			}
+		}
+	}
+	count
+}
+// Whether the cube is active.
+fn parse_cube(byte: u8) -> Result<bool, ()> {
+	match byte {
+		b'#' => Ok(true),
+		b'.' => Ok(false),
+		_ => Err(()),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 218);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 1908);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
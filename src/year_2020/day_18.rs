@@ -0,0 +1,441 @@
+// File: year_2020/day_18.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::query::Query;
+use crate::common::registry::Part;
+use crate::common::{parse_error::ParseError, *};
+
+pub struct Challenge {
+	expressions: Vec<(Expression1, Expression2)>,
+	#[cfg(feature = "reference")]
+	lines: Vec<String>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[
+		Example::new("a", Some("71"), Some("231")),
+		Example::new("b", Some("51"), Some("51")),
+		Example::new("c", Some("26"), Some("46")),
+		Example::new("d", Some("437"), Some("1445")),
+		Example::new("e", Some("12240"), Some("669060")),
+		Example::new("f", Some("13632"), Some("23340")),
+	];
+	const QUERIES: &'static [Query] = &[
+		Query::new(
+			"evaluate",
+			"<expression>",
+			"the value with + and * evaluated left to right",
+			|arguments| evaluate(arguments, Part::One),
+		),
+		Query::new(
+			"advanced",
+			"<expression>",
+			"the value with + evaluated before *",
+			|arguments| evaluate(arguments, Part::Two),
+		),
+	];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		18
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let expressions = input
+			.lines()
+			.map(|line| parse_expressions(input, line))
+			.collect::<Result<Vec<_>, ParseError>>()?;
+
+		Ok(Self {
+			expressions,
+			#[cfg(feature = "reference")]
+			lines: input.lines().map(str::to_string).collect(),
+		})
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, _>(
+			input,
+			input.lines().map(|line| parse_expressions(input, line)),
+		)
+	}
+	fn part_1(&self) -> Answer {
+		self.expressions
+			.iter()
+			.map(|(expression, _)| expression.eval())
+			.sum::<u64>()
+			.into()
+	}
+	fn part_2(&self) -> Answer {
+		self.expressions
+			.iter()
+			.map(|(_, expression)| expression.eval())
+			.sum::<u64>()
+			.into()
+	}
+	// At most 12 numbers per expression so neither order of operations overflows.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		(0..size.max(1))
+			.map(|_| generate_expression(rng, 2, &mut 12) + "\n")
+			.collect()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		// left to right
+		let flat = |tokens: &[String]| {
+			let mut value = tokens[0].parse::<u64>().unwrap();
+			for pair in tokens[1..].chunks(2) {
+				let number = pair[1].parse::<u64>().unwrap();
+				match pair[0].as_str() {
+					"+" => value += number,
+					_ => value *= number,
+				}
+			}
+			value
+		};
+		self.lines
+			.iter()
+			.map(|line| reference_eval(line, &flat))
+			.sum::<u64>()
+			.into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		// the sums between the multiplications first
+		let flat = |tokens: &[String]| {
+			tokens
+				.split(|token| token == "*")
+				.map(|sum| {
+					sum.iter()
+						.filter(|token| *token != "+")
+						.map(|number| number.parse::<u64>().unwrap())
+						.sum::<u64>()
+				})
+				.product::<u64>()
+		};
+		self.lines
+			.iter()
+			.map(|line| reference_eval(line, &flat))
+			.sum::<u64>()
+			.into()
+	}
+}
+
+// Replaces the innermost parentheses with their value until none are left, flat
+// evaluates a list of numbers and operators without parentheses.
+#[cfg(feature = "reference")]
+fn reference_eval(line: &str, flat: &dyn Fn(&[String]) -> u64) -> u64 {
+	let mut tokens = line
+		.chars()
+		.filter(|c| *c != ' ')
+		.map(|c| c.to_string())
+		.collect::<Vec<_>>();
+	while let Some(close) = tokens.iter().position(|token| token == ")") {
+		let open = tokens[..close]
+			.iter()
+			.rposition(|token| token == "(")
+			.unwrap();
+		let value = flat(&tokens[(open + 1)..close]);
+		tokens.splice(open..=close, Some(value.to_string()));
+	}
+	flat(&tokens)
+}
+
+// Terms are single digits or, above depth 0, parenthesized expressions.
+// Every expression has at least two terms and each digit uses one of the budget,
+// which must be at least 2.
+fn generate_expression(rng: &mut Rng, depth: usize, budget: &mut usize) -> String {
+	let mut expression = String::new();
+	let terms = rng.range(2..=4).min(*budget);
+	for i in 0..terms {
+		if i > 0 {
+			expression.push_str(if rng.chance(50) { " + " } else { " * " });
+		}
+		// leave a number for each of the remaining terms
+		let remaining = terms - i - 1;
+		if depth > 0 && *budget >= remaining + 2 && rng.chance(30) {
+			*budget -= remaining;
+			let inner = generate_expression(rng, depth - 1, budget);
+			*budget += remaining;
+			expression.push_str(&format!("({})", inner));
+		} else {
+			*budget -= 1;
+			expression.push((b'0' + rng.range(1..=9) as u8) as char);
+		}
+	}
+	expression
+}
+
+// A single expression evaluated with the precedence rules of the part.
+fn evaluate(arguments: &[&str], part: Part) -> Result<Answer, ParseError> {
+	let expression = arguments.join(" ");
+	let (expression_1, expression_2) = parse_expressions(&expression, &expression)?;
+	Ok(match part {
+		Part::One => expression_1.eval(),
+		Part::Two => expression_2.eval(),
+	}
+	.into())
+}
+// The line parsed with the precedence rules of both parts.
+fn parse_expressions(input: &str, line: &str) -> Result<(Expression1, Expression2), ParseError> {
+	let mut parser = Parser::new(input, line);
+	Ok((parser.parse_1()?, parser.parse_2()?))
+}
+struct Parser<'a> {
+	input: &'a str,
+	lexemes: &'a str,
+	current_i: usize,
+}
+impl<'a> Parser<'a> {
+	pub fn new(input: &'a str, lexemes: &'a str) -> Self {
+		Self {
+			input,
+			lexemes,
+			current_i: 0,
+		}
+	}
+	fn parse_1(&mut self) -> Result<Expression1, ParseError> {
+		let ast = self.parse_expression_1()?;
+		self.expect_end()?;
+		self.current_i = 0;
+		Ok(ast)
+	}
+	fn parse_2(&mut self) -> Result<Expression2, ParseError> {
+		let ast = self.parse_expression_2()?;
+		self.expect_end()?;
+		self.current_i = 0;
+		Ok(ast)
+	}
+	fn parse_expression_1(&mut self) -> Result<Expression1, ParseError> {
+		let mut expression =
+			Expression1::Atom(self.parse_atom::<Expression1>(&Self::parse_expression_1)?);
+		while let Some(op) = self.current_lexeme() {
+			// Note: '+' is 43 and '*' is 42
+			let is_add = match op {
+				43 => true,
+				42 => false,
+				_ => break,
+			};
+			self.step_lexemes();
+			let next_atom = self.parse_atom::<Expression1>(&Self::parse_expression_1)?;
+			expression = match is_add {
+				true => Expression1::Add(Box::new(expression), next_atom),
+				false => Expression1::Mul(Box::new(expression), next_atom),
+			};
+		}
+		Ok(expression)
+	}
+	fn parse_expression_2(&mut self) -> Result<Expression2, ParseError> {
+		let mut expression = Expression2::Factor(self.parse_factor_2()?);
+		while let Some(op) = self.current_lexeme() {
+			// Note: '*' is 42
+			match op {
+				42 => (),
+				_ => break,
+			}
+			self.step_lexemes();
+			expression = Expression2::Mul(Box::new(expression), self.parse_factor_2()?);
+		}
+		Ok(expression)
+	}
+	fn parse_factor_2(&mut self) -> Result<Factor, ParseError> {
+		let mut factor = Factor::Atom(self.parse_atom::<Expression2>(&Self::parse_expression_2)?);
+		while let Some(op) = self.current_lexeme() {
+			// Note: '+' is 43
+			match op {
+				43 => (),
+				_ => break,
+			}
+			self.step_lexemes();
+			factor = Factor::Add(
+				Box::new(factor),
+				self.parse_atom::<Expression2>(&Self::parse_expression_2)?,
+			);
+		}
+		Ok(factor)
+	}
+	fn parse_atom<T>(
+		&mut self,
+		parse_expression: &dyn Fn(&mut Self) -> Result<T, ParseError>,
+	) -> Result<Atom<T>, ParseError> {
+		// Note '(' is 40 and ')' is 41
+		match self.current_lexeme() {
+			Some(40) => {
+				self.step_lexemes();
+				let expresssion = parse_expression(self)?;
+				match self.current_lexeme() {
+					Some(41) => self.step_lexemes(),
+					_ => return Err(self.error("expected ')'")),
+				}
+				Ok(Atom::Paren(Box::new(expresssion)))
+			}
+			Some(n) if n.is_ascii_digit() => {
+				let num = (n - 48) as u64;
+				self.step_lexemes();
+				Ok(Atom::Number(num))
+			}
+			_ => Err(self.error("expected a digit or '('")),
+		}
+	}
+	fn expect_end(&mut self) -> Result<(), ParseError> {
+		match self.current_lexeme() {
+			Some(_) => Err(self.error("expected '+', '*' or the end of the expression")),
+			None => Ok(()),
+		}
+	}
+	fn error(&self, message: &str) -> ParseError {
+		ParseError::at(
+			Challenge::year(),
+			Challenge::day(),
+			self.input,
+			&self.lexemes[self.current_i..],
+			message,
+		)
+	}
+
+	fn current_lexeme(&mut self) -> Option<u8> {
+		let l = *self.lexemes.as_bytes().get(self.current_i)?;
+		// Note: ' ' is 32
+		if l == 32 {
+			self.step_lexemes();
+			self.current_lexeme()
+		} else {
+			Some(l)
+		}
+	}
+	fn step_lexemes(&mut self) {
+		self.current_i += 1;
+	}
+}
+
+trait EvalT {
+	fn eval(&self) -> u64;
+}
+#[derive(Debug, Clone)]
+enum Expression1 {
+	Add(Box<Expression1>, Atom<Expression1>),
+	Mul(Box<Expression1>, Atom<Expression1>),
+	Atom(Atom<Expression1>),
+}
+impl EvalT for Expression1 {
+	fn eval(&self) -> u64 {
+		match self {
+			Expression1::Add(lhs, rhs) => lhs.eval() + rhs.eval(),
+			Expression1::Mul(lhs, rhs) => lhs.eval() * rhs.eval(),
+			Expression1::Atom(atom) => atom.eval(),
+		}
+	}
+}
+#[derive(Debug, Clone)]
+enum Expression2 {
+	Mul(Box<Expression2>, Factor),
+	Factor(Factor),
+}
+impl EvalT for Expression2 {
+	fn eval(&self) -> u64 {
+		match self {
+			Expression2::Mul(lhs, rhs) => lhs.eval() * rhs.eval(),
+			Expression2::Factor(factor) => factor.eval(),
+		}
+	}
+}
+#[derive(Debug, Clone)]
+enum Factor {
+	Add(Box<Factor>, Atom<Expression2>),
+	Atom(Atom<Expression2>),
+}
+impl EvalT for Factor {
+	fn eval(&self) -> u64 {
+		match self {
+			Factor::Add(lhs, rhs) => lhs.eval() + rhs.eval(),
+			Factor::Atom(atom) => atom.eval(),
+		}
+	}
+}
+#[derive(Debug, Clone)]
+enum Atom<T> {
+	Number(u64),
+	Paren(Box<T>),
+}
+impl<T> EvalT for Atom<T>
+where
+	T: EvalT,
+{
+	fn eval(&self) -> u64 {
+		match self {
+			Atom::Paren(expression) => expression.eval(),
+			Atom::Number(n) => *n,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::registry::Part;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 14006719520523u64);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 545115449981968u64);
+	}
+	#[test]
+	fn query_test() {
+		let expression = [
+			"5", "+", "(8", "*", "3", "+", "9", "+", "3", "*", "4", "*", "3)",
+		];
+		assert_eq!(super::evaluate(&expression, Part::One).unwrap(), 437);
+		assert_eq!(super::evaluate(&expression, Part::Two).unwrap(), 1445);
+		assert!(super::evaluate(&["1", "+"], Part::One).is_err());
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn part_1_bench(b: &mut Bencher) {
+		b.iter(|| Challenge::new().unwrap().part_1())
+	}
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn part_2_bench(b: &mut Bencher) {
+		b.iter(|| Challenge::new().unwrap().part_2())
+	}
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
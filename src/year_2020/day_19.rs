@@ -0,0 +1,428 @@
+// File: year_2020/day_19.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::{
+	parse_error::{parse_number, ParseError},
+	*,
+};
+use std::collections::HashMap;
+
+pub struct Challenge {
+	rules: Vec<Rule>,
+	messages: Vec<Vec<char>>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[
+		Example::new("example", Some("2"), None),
+		Example::new("looping", Some("3"), Some("12")),
+	];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		19
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let year = Self::year();
+		let day = Self::day();
+		let mut input_split = input.split("\n\n");
+		let mut rules = vec![Rule::Alternatives(Vec::new()); RULE_COUNT];
+		let mut defined = [false; RULE_COUNT];
+		let mut references = Vec::new();
+		for line in input_split.next().unwrap_or_default().lines() {
+			let (name, parsed, rule_references) = parse_rule(input, line)?;
+			references.extend(rule_references);
+			rules[name] = parsed;
+			defined[name] = true;
+		}
+		for reference in references {
+			if !defined[parse_rule_number(input, reference)?] {
+				return Err(ParseError::at(
+					year,
+					day,
+					input,
+					reference,
+					format!("rule {} is never defined", reference),
+				));
+			}
+		}
+		if !defined[0] {
+			return Err(ParseError::new(year, day, 0, 0, "rule 0 is never defined"));
+		}
+
+		let messages = input_split.next().ok_or_else(|| {
+			ParseError::at(year, day, input, &input[input.len()..], "expected messages")
+		})?;
+		let messages = messages
+			.lines()
+			.map(|line| line.chars().collect::<Vec<_>>())
+			.collect();
+
+		Ok(Self { rules, messages })
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, _>(
+			input,
+			input
+				.split("\n\n")
+				.next()
+				.unwrap_or_default()
+				.lines()
+				.map(|line| parse_rule(input, line)),
+		)
+	}
+	fn part_1(&self) -> Answer {
+		self.messages
+			.iter()
+			.filter(|message| matches(message, &self.rules))
+			.count()
+			.into()
+	}
+	fn part_2(&self) -> Answer {
+		let mut rules = self.rules.clone();
+		rules[8] = Rule::Alternatives(vec![vec![42], vec![42, 8]]);
+		rules[11] = Rule::Alternatives(vec![vec![42, 31], vec![42, 11, 31]]);
+		self.messages
+			.iter()
+			.filter(|message| matches(message, &rules))
+			.count()
+			.into()
+	}
+	// Like the puzzle, 0 is "8 11", 8 is "42" and 11 is "42 31" where 42 and 31 are built
+	// from letters through a few layers of rules. Half of the messages come from rule 0 with
+	// the looping rules of part 2 and the others are random.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let mut numbers = (1..RULE_COUNT)
+			.filter(|n| ![8, 11, 31, 42].contains(n))
+			.collect::<Vec<_>>();
+		rng.shuffle(&mut numbers);
+		let mut numbers = numbers.into_iter();
+		let mut rules = vec![Rule::Alternatives(Vec::new()); RULE_COUNT];
+		let mut layer = ['a', 'b']
+			.iter()
+			.map(|letter| {
+				let number = numbers.next().unwrap();
+				rules[number] = Rule::Letter(*letter);
+				number
+			})
+			.collect::<Vec<_>>();
+		let depth = rng.range(1..=3);
+		for d in 1..=depth {
+			let targets = if d == depth {
+				vec![42, 31]
+			} else {
+				(0..3).map(|_| numbers.next().unwrap()).collect()
+			};
+			for &number in targets.iter() {
+				// a message matching a rule in more than one way would make matching slow
+				loop {
+					let alternatives = (0..rng.range(1..=2))
+						.map(|_| vec![*rng.choose(&layer), *rng.choose(&layer)])
+						.collect();
+					rules[number] = Rule::Alternatives(alternatives);
+					if language(&rules, number).values().all(|ways| *ways == 1) {
+						break;
+					}
+				}
+			}
+			layer = targets;
+		}
+		rules[0] = Rule::Alternatives(vec![vec![8, 11]]);
+		rules[8] = Rule::Alternatives(vec![vec![42]]);
+		rules[11] = Rule::Alternatives(vec![vec![42, 31]]);
+
+		let mut lines = Vec::new();
+		for (number, rule) in rules.iter().enumerate() {
+			match rule {
+				Rule::Letter(letter) => lines.push(format!("{}: \"{}\"", number, letter)),
+				Rule::Alternatives(alternatives) if !alternatives.is_empty() => {
+					let sequences = alternatives
+						.iter()
+						.map(|sequence| {
+							let numbers =
+								sequence.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+							numbers.join(" ")
+						})
+						.collect::<Vec<_>>();
+					lines.push(format!("{}: {}", number, sequences.join(" | ")));
+				}
+				Rule::Alternatives(_) => (),
+			}
+		}
+		rng.shuffle(&mut lines);
+
+		let messages = (0..size.max(1))
+			.map(|_| {
+				let mut message = String::new();
+				if rng.chance(50) {
+					let closing = rng.range(1..=2);
+					for _ in 0..(closing + rng.range(1..=3)) {
+						generate_message(rng, &rules, 42, &mut message);
+					}
+					for _ in 0..closing {
+						generate_message(rng, &rules, 31, &mut message);
+					}
+				} else {
+					let len = rng.range(1..=(3 << depth));
+					message.extend((0..len).map(|_| if rng.chance(50) { 'a' } else { 'b' }));
+				}
+				message
+			})
+			.collect::<Vec<_>>();
+		format!("{}\n\n{}\n", lines.join("\n"), messages.join("\n"))
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		self.messages
+			.iter()
+			.filter(|message| reference_matches(&self.rules, message))
+			.count()
+			.into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		let mut rules = self.rules.clone();
+		rules[8] = Rule::Alternatives(vec![vec![42], vec![42, 8]]);
+		rules[11] = Rule::Alternatives(vec![vec![42, 31], vec![42, 11, 31]]);
+		self.messages
+			.iter()
+			.filter(|message| reference_matches(&rules, message))
+			.count()
+			.into()
+	}
+}
+
+// Whether rule 0 matches the whole message, trying every way to split it between the
+// rules of a sequence. Every rule matches at least one letter so a looping rule always
+// has less of the message left when it comes back around.
+#[cfg(feature = "reference")]
+fn reference_matches(rules: &[Rule], message: &[char]) -> bool {
+	rule_matches(rules, message, 0, 0, message.len(), &mut HashMap::new())
+}
+// Whether the rule matches exactly message[start..end].
+#[cfg(feature = "reference")]
+fn rule_matches(
+	rules: &[Rule],
+	message: &[char],
+	rule: usize,
+	start: usize,
+	end: usize,
+	known: &mut HashMap<(usize, usize, usize), bool>,
+) -> bool {
+	if let Some(matched) = known.get(&(rule, start, end)) {
+		return *matched;
+	}
+	let matched = match &rules[rule] {
+		Rule::Letter(letter) => end == start + 1 && message[start] == *letter,
+		Rule::Alternatives(alternatives) => alternatives
+			.iter()
+			.any(|sequence| sequence_matches(rules, message, sequence, start, end, known)),
+	};
+	known.insert((rule, start, end), matched);
+	matched
+}
+#[cfg(feature = "reference")]
+fn sequence_matches(
+	rules: &[Rule],
+	message: &[char],
+	sequence: &[usize],
+	start: usize,
+	end: usize,
+	known: &mut HashMap<(usize, usize, usize), bool>,
+) -> bool {
+	match sequence {
+		[] => start == end,
+		[rule] => rule_matches(rules, message, *rule, start, end, known),
+		[first, rest @ ..] => ((start + 1)..end).any(|split| {
+			rule_matches(rules, message, *first, start, split, known)
+				&& sequence_matches(rules, message, rest, split, end, known)
+		}),
+	}
+}
+
+// Every message a rule matches and the number of ways it matches, rules must not loop.
+fn language(rules: &[Rule], rule: usize) -> HashMap<String, usize> {
+	match &rules[rule] {
+		Rule::Letter(letter) => std::iter::once((letter.to_string(), 1)).collect(),
+		Rule::Alternatives(alternatives) => {
+			let mut messages = HashMap::new();
+			for sequence in alternatives.iter() {
+				let sequence_messages = sequence.iter().fold(
+					std::iter::once((String::new(), 1)).collect::<HashMap<_, _>>(),
+					|prefixes, &next| {
+						let mut messages = HashMap::new();
+						for (suffix, suffix_ways) in language(rules, next) {
+							for (prefix, ways) in prefixes.iter() {
+								*messages.entry(format!("{}{}", prefix, suffix)).or_insert(0) +=
+									ways * suffix_ways;
+							}
+						}
+						messages
+					},
+				);
+				for (message, ways) in sequence_messages {
+					*messages.entry(message).or_insert(0) += ways;
+				}
+			}
+			messages
+		}
+	}
+}
+// Appends a random message that matches the rule.
+fn generate_message(rng: &mut Rng, rules: &[Rule], rule: usize, message: &mut String) {
+	match &rules[rule] {
+		Rule::Letter(letter) => message.push(*letter),
+		Rule::Alternatives(alternatives) => {
+			for &next in rng.choose(alternatives).iter() {
+				generate_message(rng, rules, next, message);
+			}
+		}
+	}
+}
+
+const RULE_COUNT: usize = 200;
+// A rule's number, the rule and the numbers of the rules it refers to.
+fn parse_rule<'a>(input: &str, line: &'a str) -> Result<(usize, Rule, Vec<&'a str>), ParseError> {
+	let year = Challenge::year();
+	let day = Challenge::day();
+	let mut references = Vec::new();
+	let (name, rule) = line
+		.split_once(": ")
+		.ok_or_else(|| ParseError::at(year, day, input, line, "expected \"<number>: <rule>\""))?;
+	let name = parse_rule_number(input, name)?;
+	let parsed = if rule.contains('"') {
+		let letter = rule
+			.split('\"')
+			.nth(1)
+			.and_then(|letter| letter.chars().next())
+			.ok_or_else(|| ParseError::at(year, day, input, rule, "expected a letter"))?;
+		Rule::Letter(letter)
+	} else {
+		let mut alternatives = Vec::new();
+		for sequence in rule.split('|') {
+			let sequence = sequence
+				.split_whitespace()
+				.map(|lexeme| {
+					references.push(lexeme);
+					parse_rule_number(input, lexeme)
+				})
+				.collect::<Result<Vec<_>, _>>()?;
+			if sequence.is_empty() {
+				return Err(ParseError::at(
+					year,
+					day,
+					input,
+					rule,
+					format!("unexpected rule \"{}\"", rule),
+				));
+			}
+			alternatives.push(sequence);
+		}
+		Rule::Alternatives(alternatives)
+	};
+	Ok((name, parsed, references))
+}
+fn parse_rule_number(input: &str, token: &str) -> Result<usize, ParseError> {
+	match parse_number(Challenge::year(), Challenge::day(), input, token)? {
+		n if n < RULE_COUNT => Ok(n),
+		_ => Err(ParseError::at(
+			Challenge::year(),
+			Challenge::day(),
+			input,
+			token,
+			format!("rule numbers must be less than {}", RULE_COUNT),
+		)),
+	}
+}
+
+#[derive(Debug, Clone)]
+enum Rule {
+	// i: "c"
+	Letter(char),
+	// i: a1 a2 ... | b1 b2 ... | ...
+	Alternatives(Vec<Vec<usize>>),
+}
+fn matches(message: &[char], rules: &[Rule]) -> bool {
+	match_rule(message, rules, 0, 0).contains(&message.len())
+}
+// Every position the rule can end at when it starts at start. Keeping all of them lets
+// a sequence backtrack into another alternative and lets rules loop, as long as every
+// loop consumes a letter before it recurses.
+fn match_rule(message: &[char], rules: &[Rule], rule: usize, start: usize) -> Vec<usize> {
+	match &rules[rule] {
+		Rule::Letter(c) => match message.get(start) {
+			Some(m) if m == c => vec![start + 1],
+			_ => Vec::new(),
+		},
+		Rule::Alternatives(alternatives) => alternatives
+			.iter()
+			.flat_map(|sequence| {
+				sequence.iter().fold(vec![start], |ends, &next| {
+					ends.into_iter()
+						.flat_map(|end| match_rule(message, rules, next, end))
+						.collect()
+				})
+			})
+			.collect(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 239);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 405);
+	}
+	#[test]
+	fn parse_error_test() {
+		let error = Challenge::from_input("0: 1 2\n1: \"a\"\n2: 1 | 3\n\naab")
+			.err()
+			.unwrap();
+		assert_eq!((error.day, error.line, error.column), (19, 3, 8));
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
@@ -0,0 +1,201 @@
+// File: year_2020/day_2.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::lint;
+use crate::common::parse_error::{parse_number, ParseError};
+use crate::common::ChallengeT;
+
+pub struct Challenge {
+	parsed_lines: Vec<(u16, u16, u8, String)>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[Example::new("example", Some("2"), Some("1"))];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		2
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		Ok(Self {
+			parsed_lines: input
+				.lines()
+				.map(|line| parse_line(input, line))
+				.collect::<Result<_, _>>()?,
+		})
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, _>(input, input.lines().map(|line| parse_line(input, line)))
+	}
+	fn part_1(&self) -> Answer {
+		self.parsed_lines
+			.iter()
+			.filter(|(min, max, letter, password)| {
+				let count = password
+					.bytes()
+					.fold(0, |acc, c| if c == *letter { acc + 1 } else { acc });
+				count >= *min && count <= *max
+			})
+			.count()
+			.into()
+	}
+	fn part_2(&self) -> Answer {
+		self.parsed_lines
+			.iter()
+			.filter(|(first_pos, second_pos, letter, password)| {
+				let (first_i, second_i) = ((first_pos - 1) as usize, (second_pos - 1) as usize);
+				let [first_letter, second_letter] = password[0..(*second_pos as usize)]
+					.bytes()
+					.enumerate()
+					.fold(*b"  ", |[first_letter, second_letter], (i, c)| {
+						if i == first_i {
+							[c, second_letter]
+						} else if i == second_i {
+							[first_letter, c]
+						} else {
+							[first_letter, second_letter]
+						}
+					});
+				// exactly one of the positions, so the same position twice never matches
+				first_pos != second_pos
+					&& first_letter != second_letter
+					&& (*letter == first_letter || *letter == second_letter)
+			})
+			.count()
+			.into()
+	}
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		(0..size.max(1))
+			.map(|_| {
+				let letter = (b'a' + rng.below(26) as u8) as char;
+				// mostly the policy's letter so the counts land on both sides of the range
+				let password = (0..rng.range(1..=20))
+					.map(|_| match rng.chance(40) {
+						true => letter,
+						false => (b'a' + rng.below(26) as u8) as char,
+					})
+					.collect::<String>();
+				let max = rng.range(1..=password.len());
+				let min = rng.range(1..=max);
+				format!("{}-{} {}: {}\n", min, max, letter, password)
+			})
+			.collect()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		self.parsed_lines
+			.iter()
+			.filter(|(min, max, letter, password)| {
+				let count = password.bytes().filter(|c| c == letter).count();
+				*min as usize <= count && count <= *max as usize
+			})
+			.count()
+			.into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		self.parsed_lines
+			.iter()
+			.filter(|(first, second, letter, password)| {
+				let at =
+					|position: u16| password.as_bytes().get(position as usize - 1) == Some(letter);
+				at(*first) != at(*second)
+			})
+			.count()
+			.into()
+	}
+}
+fn parse_line(input: &str, line: &str) -> Result<(u16, u16, u8, String), ParseError> {
+	let year = Challenge::year();
+	let day = Challenge::day();
+	let mut parts = line.split(&['-', ' '][..]);
+	let mut next_part = |expected: &str| {
+		parts
+			.next()
+			.filter(|part| !part.is_empty())
+			.ok_or_else(|| ParseError::at(year, day, input, line, format!("expected {}", expected)))
+	};
+	let min: u16 = parse_number(year, day, input, next_part("a minimum")?)?;
+	let max: u16 = parse_number(year, day, input, next_part("a maximum")?)?;
+	let letter = next_part("a letter")?.as_bytes()[0];
+	let password = next_part("a password")?.to_owned();
+	if min == 0 || min > max || max as usize > password.len() {
+		return Err(ParseError::at(
+			year,
+			day,
+			input,
+			line,
+			format!("{}-{} is not a valid range for \"{}\"", min, max, password),
+		));
+	}
+	Ok((min, max, letter, password))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 517);
+	}
+	#[test]
+	fn part_2() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 284);
+	}
+	#[test]
+	fn same_position_test() {
+		// the letter at the same position twice is not at exactly one of them
+		let challenge = Challenge::from_input("1-1 a: abc\n1-2 a: abc\n").unwrap();
+		assert_eq!(challenge.part_2(), 1);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn part_1_bench(b: &mut Bencher) {
+		b.iter(|| Challenge::new().unwrap().part_1())
+	}
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn part_2_bench(b: &mut Bencher) {
+		b.iter(|| Challenge::new().unwrap().part_2())
+	}
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
@@ -0,0 +1,770 @@
+// File: year_2020/day_20.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::{
+	grid::*,
+	parse_error::{parse_number, ParseError},
+	*,
+};
+use std::cmp::Ord;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+pub struct Challenge {
+	tiles: Vec<Tile>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] =
+		&[Example::new("example", Some("20899048083289"), Some("273"))];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		20
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let tiles = parse_input(input)?;
+		Ok(Self { tiles })
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, _>(
+			input,
+			input.split("\n\n").map(|tile| parse_tile(input, tile)),
+		)
+	}
+	fn part_1(&self) -> Answer {
+		solve_1(&build_adjacency_list(&self.tiles)).into()
+	}
+	fn part_2(&self) -> Answer {
+		solve_2(&self.tiles, &build_adjacency_list(&self.tiles)).into()
+	}
+	// A square image cut into tiles that share their borders with their neighbours. No
+	// border is used twice either way around so every tile only fits one way, then each
+	// tile is turned and flipped at random. The sea monsters all face the same way and
+	// never overlap.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let side = size.clamp(3, 12);
+		let len = 9 * side + 1;
+		let mut pixels = vec![vec![false; len]; len];
+		for (y, row) in pixels.iter_mut().enumerate() {
+			for (x, pixel) in row.iter_mut().enumerate() {
+				*pixel = match (x % 9, y % 9) {
+					(0, 0) => rng.chance(50),
+					(0, _) | (_, 0) => false,
+					_ => rng.chance(25),
+				};
+			}
+		}
+
+		let mut used = HashSet::new();
+		for i in 0..=side {
+			for j in 0..side {
+				for &horizontal in [true, false].iter() {
+					let cell = |k: usize| {
+						if horizontal {
+							(9 * j + k, 9 * i)
+						} else {
+							(9 * i, 9 * j + k)
+						}
+					};
+					loop {
+						let middle = rng.below(256);
+						let border = (0..10)
+							.map(|k| match k {
+								0 | 9 => {
+									let (x, y) = cell(k);
+									pixels[y][x]
+								}
+								_ => (middle >> (k - 1)) & 1 == 1,
+							})
+							.collect::<Vec<_>>();
+						let reversed = border.iter().rev().copied().collect::<Vec<_>>();
+						if border != reversed
+							&& !used.contains(&border)
+							&& !used.contains(&reversed)
+						{
+							for (k, &pixel) in border.iter().enumerate() {
+								let (x, y) = cell(k);
+								pixels[y][x] = pixel;
+							}
+							used.insert(border);
+							break;
+						}
+					}
+				}
+			}
+		}
+
+		let image_len = 8 * side;
+		let transform = Transform::ALL[rng.below(8)];
+		let monster = sea_monster().transformed(transform);
+		let (width, height) = (monster.size.x, monster.size.y);
+		let monster = monster.cells().map(|(cell, _)| *cell).collect::<Vec<_>>();
+		// the row and column in the tiles of a pixel in the image without borders
+		let cell = |x: usize, y: usize| (9 * (y / 8) + 1 + y % 8, 9 * (x / 8) + 1 + x % 8);
+		let mut planted = Vec::<(usize, usize)>::new();
+		for _ in 0..rng.range(1..=side * side / 4) {
+			let x = rng.below(image_len - width + 1);
+			let y = rng.below(image_len - height + 1);
+			let overlaps = planted.iter().any(|&(other_x, other_y)| {
+				x < other_x + width
+					&& other_x < x + width
+					&& y < other_y + height
+					&& other_y < y + height
+			});
+			if overlaps {
+				continue;
+			}
+			planted.push((x, y));
+			for (dx, dy) in monster.iter() {
+				let (row, column) = cell(x + dx, y + dy);
+				pixels[row][column] = true;
+			}
+		}
+		// Monsters planted close together and the noise can form more monsters, even in
+		// other orientations, so one pixel that wasn't planted is cleared from each of them.
+		let planted_pixels = planted
+			.iter()
+			.flat_map(|(x, y)| monster.iter().map(move |(dx, dy)| (x + dx, y + dy)))
+			.collect::<HashSet<_>>();
+		for other in Transform::all() {
+			let other_monster = sea_monster().transformed(other);
+			let (width, height) = (other_monster.size.x, other_monster.size.y);
+			let other_monster = other_monster
+				.cells()
+				.map(|(cell, _)| *cell)
+				.collect::<Vec<_>>();
+			for y in 0..=(image_len - height) {
+				for x in 0..=(image_len - width) {
+					if other == transform && planted.contains(&(x, y)) {
+						continue;
+					}
+					let found = other_monster.iter().all(|(dx, dy)| {
+						let (row, column) = cell(x + dx, y + dy);
+						pixels[row][column]
+					});
+					if !found {
+						continue;
+					}
+					let unplanted = other_monster
+						.iter()
+						.map(|(dx, dy)| (x + dx, y + dy))
+						.find(|pixel| !planted_pixels.contains(pixel));
+					if let Some((x, y)) = unplanted {
+						let (row, column) = cell(x, y);
+						pixels[row][column] = false;
+					}
+				}
+			}
+		}
+
+		let mut ids = (1000..=9999).collect::<Vec<u32>>();
+		rng.shuffle(&mut ids);
+		let mut tiles = Vec::with_capacity(side * side);
+		for tile_y in 0..side {
+			for tile_x in 0..side {
+				let transform = Transform::ALL[rng.below(8)];
+				let mut tile = vec![vec!['.'; 10]; 10];
+				for y in 0..10 {
+					for x in 0..10 {
+						let (dx, dy) = transform.apply((x, y), (10, 10));
+						if pixels[9 * tile_y + y][9 * tile_x + x] {
+							tile[dy][dx] = '#';
+						}
+					}
+				}
+				let rows = tile
+					.iter()
+					.map(|row| row.iter().collect::<String>())
+					.collect::<Vec<_>>();
+				tiles.push(format!("Tile {}:\n{}", ids[tiles.len()], rows.join("\n")));
+			}
+		}
+		rng.shuffle(&mut tiles);
+		tiles.join("\n\n")
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		let placed = match self.reference_assemble() {
+			Some(placed) => placed,
+			None => return Answer::NotApplicable,
+		};
+		let side = image_side(&self.tiles);
+		[0, side - 1, side * (side - 1), side * side - 1]
+			.iter()
+			.map(|corner| placed[*corner].0 as u64)
+			.product::<u64>()
+			.into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		let placed = match self.reference_assemble() {
+			Some(placed) => placed,
+			None => return Answer::NotApplicable,
+		};
+		// the image without the borders of the tiles
+		let side = image_side(&self.tiles);
+		let tile_len = placed[0].1.len() - 2;
+		let len = side * tile_len;
+		let image = (0..len)
+			.map(|y| {
+				(0..len)
+					.map(|x| {
+						let tile = &placed[(y / tile_len) * side + x / tile_len].1;
+						tile[y % tile_len + 1][x % tile_len + 1]
+					})
+					.collect()
+			})
+			.collect::<Pixels>();
+
+		let rows = SEA_MONSTER.lines().collect::<Vec<_>>();
+		let monster = rows
+			.iter()
+			.enumerate()
+			.flat_map(|(y, row)| {
+				row.bytes()
+					.enumerate()
+					.filter(|(_, b)| *b == b'#')
+					.map(move |(x, _)| (x, y))
+			})
+			.collect::<Vec<_>>();
+		for image in reference_orientations(&image) {
+			let mut in_monster = vec![vec![false; len]; len];
+			let mut found = false;
+			for y in 0..=(len - rows.len()) {
+				for x in 0..=(len - rows[0].len()) {
+					if monster.iter().all(|(dx, dy)| image[y + dy][x + dx]) {
+						found = true;
+						for (dx, dy) in monster.iter() {
+							in_monster[y + dy][x + dx] = true;
+						}
+					}
+				}
+			}
+			if found {
+				return image
+					.iter()
+					.flatten()
+					.zip(in_monster.iter().flatten())
+					.filter(|(pixel, in_monster)| **pixel && !**in_monster)
+					.count()
+					.into();
+			}
+		}
+		Answer::NotApplicable
+	}
+}
+
+#[cfg(feature = "reference")]
+type Pixels = Vec<Vec<bool>>;
+#[cfg(feature = "reference")]
+impl Challenge {
+	// The tiles in reading order, turned and flipped so every border matches its neighbour.
+	fn reference_assemble(&self) -> Option<Vec<(u32, Pixels)>> {
+		// every tile's pixels with '#' as true
+		let options = self
+			.tiles
+			.iter()
+			.map(|tile| {
+				let size = tile.pixels.size;
+				let pixels = (0..size.y)
+					.map(|y| {
+						(0..size.x)
+							.map(|x| tile.pixels[(x, y)] == Pixel::Black)
+							.collect()
+					})
+					.collect();
+				(tile.id, reference_orientations(&pixels))
+			})
+			.collect::<Vec<_>>();
+		let mut placed = Vec::with_capacity(options.len());
+		let mut used = vec![false; options.len()];
+		if reference_place(&options, image_side(&self.tiles), &mut placed, &mut used) {
+			Some(placed)
+		} else {
+			None
+		}
+	}
+}
+// Tries every unused tile in every orientation that matches the tiles above and to the
+// left of the next position, backtracking when none fit.
+#[cfg(feature = "reference")]
+fn reference_place(
+	options: &[(u32, Vec<Pixels>)],
+	side: usize,
+	placed: &mut Vec<(u32, Pixels)>,
+	used: &mut [bool],
+) -> bool {
+	let position = placed.len();
+	if position == options.len() {
+		return true;
+	}
+	for (i, (id, orientations)) in options.iter().enumerate() {
+		if used[i] {
+			continue;
+		}
+		for pixels in orientations.iter() {
+			let last = pixels.len() - 1;
+			let fits_left = position.is_multiple_of(side) || {
+				let left = &placed[position - 1].1;
+				(0..=last).all(|y| left[y][last] == pixels[y][0])
+			};
+			let fits_above = position < side || placed[position - side].1[last] == pixels[0];
+			if fits_left && fits_above {
+				used[i] = true;
+				placed.push((*id, pixels.clone()));
+				if reference_place(options, side, placed, used) {
+					return true;
+				}
+				placed.pop();
+				used[i] = false;
+			}
+		}
+	}
+	false
+}
+// The 8 ways a square grid can be turned and flipped.
+#[cfg(feature = "reference")]
+fn reference_orientations(pixels: &Pixels) -> Vec<Pixels> {
+	let len = pixels.len();
+	let mut orientations = Vec::with_capacity(8);
+	let mut turned = pixels.clone();
+	for _ in 0..4 {
+		let flipped = turned
+			.iter()
+			.map(|row| row.iter().rev().copied().collect())
+			.collect();
+		// a quarter turn clockwise
+		let next = (0..len)
+			.map(|x| (0..len).rev().map(|y| turned[y][x]).collect())
+			.collect();
+		orientations.push(std::mem::replace(&mut turned, next));
+		orientations.push(flipped);
+	}
+	orientations
+}
+
+const SEA_MONSTER: &str = concat!(
+	"                  # \n",
+	"#    ##    ##    ###\n",
+	" #  #  #  #  #  #   ",
+);
+fn sea_monster() -> Pattern<Pixel> {
+	Pattern::parse(SEA_MONSTER, b' ', |_| Pixel::Black)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Tile>, ParseError> {
+	let year = Challenge::year();
+	let day = Challenge::day();
+	let mut tiles = Vec::<Tile>::with_capacity(144);
+	for s in input.split("\n\n") {
+		tiles.push(parse_tile(input, s)?);
+	}
+	tiles.sort();
+	let side = image_side(&tiles);
+	if side * side != tiles.len() {
+		return Err(ParseError::new(
+			year,
+			day,
+			0,
+			0,
+			format!("{} tiles can not form a square image", tiles.len()),
+		));
+	}
+	Ok(tiles)
+}
+fn parse_tile(input: &str, s: &str) -> Result<Tile, ParseError> {
+	let year = Challenge::year();
+	let day = Challenge::day();
+	let (header, pixels) = s.split_once('\n').unwrap_or((s, ""));
+	let id = header
+		.strip_prefix("Tile ")
+		.and_then(|header| header.strip_suffix(':'))
+		.ok_or_else(|| ParseError::at(year, day, input, header, "expected \"Tile <id>:\""))?;
+	let id = parse_number::<u32>(year, day, input, id)?;
+	let pixels =
+		Grid::parse(year, day, pixels, Pixel::try_from).map_err(|e| e.within(input, pixels))?;
+	if (pixels.size.x, pixels.size.y) != (10, 10) {
+		return Err(ParseError::at(
+			year,
+			day,
+			input,
+			header,
+			format!(
+				"expected 10x10 pixels but found {}x{}",
+				pixels.size.x, pixels.size.y
+			),
+		));
+	}
+
+	// read left to right and top to bottom with a bit set for every white pixel
+	let boarder = |cells: &mut dyn Iterator<Item = (usize, usize)>| {
+		cells.fold(0, |side, cell| {
+			side << 1 | (pixels[cell] == Pixel::White) as u16
+		})
+	};
+	let top = boarder(&mut (0..10).map(|x| (x, 0)));
+	let right = boarder(&mut (0..10).map(|y| (9, y)));
+	let bottom = boarder(&mut (0..10).map(|x| (x, 9)));
+	let left = boarder(&mut (0..10).map(|y| (0, y)));
+
+	Ok(Tile::new(id, &pixels, &[top, right, bottom, left]))
+}
+fn image_side(tiles: &[Tile]) -> usize {
+	(tiles.len() as f64).sqrt().round() as usize
+}
+fn build_adjacency_list(tiles: &[Tile]) -> HashMap<u32, Vec<u32>> {
+	tiles
+		.iter()
+		.map(|tile| {
+			let tile_boarders = tile.all_boarders_iter().cloned().collect::<Vec<_>>();
+			let adjacent = tiles
+				.iter()
+				.filter_map(|other| {
+					if other.id == tile.id {
+						None
+					} else {
+						for other_boarder in other.all_boarders_iter() {
+							for tile_boarder in tile_boarders.iter() {
+								if *other_boarder == *tile_boarder {
+									return Some(other.id);
+								}
+							}
+						}
+						None
+					}
+				})
+				.collect();
+			(tile.id, adjacent)
+		})
+		.collect()
+}
+fn solve_1(adjacency_list: &HashMap<u32, Vec<u32>>) -> usize {
+	let mut product = 1;
+	for (id, a) in adjacency_list.iter() {
+		if a.len() == 2 {
+			product *= *id as usize;
+		}
+	}
+	product
+}
+
+fn solve_2(tiles: &[Tile], adjacency_list: &HashMap<u32, Vec<u32>>) -> usize {
+	let mut start = tiles[0].clone();
+	for (id, adjacent_ids) in adjacency_list.iter() {
+		let i = tiles.binary_search_by(|t| t.id.cmp(id)).unwrap();
+		if 2 == adjacent_ids.len() {
+			start = tiles[i].clone();
+			break;
+		}
+	}
+
+	let side = image_side(tiles);
+	let mut img = Grid::<Option<Tile>>::new(side, side, &None);
+
+	let starting_adjacent = adjacency_list
+		.get(&start.id)
+		.unwrap()
+		.iter()
+		.map(|id| {
+			let i = tiles.binary_search_by(|t| t.id.cmp(id)).unwrap();
+			tiles[i].clone()
+		})
+		.collect::<Vec<_>>();
+
+	let a = starting_adjacent.first().unwrap();
+	let b = starting_adjacent.get(1).unwrap();
+
+	let (s1, s1_rev) = shared_side_value(&start, a);
+	let (s2, s2_rev) = shared_side_value(&start, b);
+
+	let start_in_correct_orientation =
+		|right: u16, bottom: u16, s1: u16, s1_rev: u16, s2: u16, s2_rev: u16| {
+			((right == s1 || right == s1_rev) && (bottom == s2 || bottom == s2_rev))
+				|| ((right == s2 || right == s2_rev) && (bottom == s1 || bottom == s1_rev))
+		};
+	let transform = Transform::all()
+		.find(|transform| {
+			let turned = start.transformed(*transform);
+			let right = turned.get_boarder(Side::Right);
+			let bottom = turned.get_boarder(Side::Bottom);
+			start_in_correct_orientation(right, bottom, s1, s1_rev, s2, s2_rev)
+		})
+		.unwrap();
+	start.transform(transform);
+	img.set(0, 0, &Some(start));
+
+	for y in 1..side {
+		let previous = img.get(0, y - 1).unwrap().as_ref().unwrap();
+		let side_to_match = previous.get_boarder(Side::Bottom);
+		let adjacent = adjacency_list.get(&previous.id).unwrap();
+		let mut current = None;
+		for id in adjacent.iter() {
+			let i = match tiles.binary_search_by(|t| t.id.cmp(id)) {
+				Ok(i) => i,
+				Err(_) => continue,
+			};
+			for s in tiles[i].all_boarders_iter() {
+				if *s == side_to_match {
+					current = Some(tiles[i].clone());
+					break;
+				}
+			}
+			if current.is_some() {
+				break;
+			}
+		}
+		let mut current = current.unwrap();
+		orient_tile(&mut current, Side::Top, side_to_match);
+		img.set(0, y, &Some(current));
+	}
+	for y in 0..side {
+		for x in 1..side {
+			let previous = img.get(x - 1, y).unwrap().as_ref().unwrap();
+			let side_to_match = previous.get_boarder(Side::Right);
+			let adjacent = adjacency_list.get(&previous.id).unwrap();
+			let mut current = None;
+			for id in adjacent.iter() {
+				let i = match tiles.binary_search_by(|t| t.id.cmp(id)) {
+					Ok(i) => i,
+					Err(_) => continue,
+				};
+				for s in tiles[i].all_boarders_iter() {
+					if *s == side_to_match {
+						current = Some(tiles[i].clone());
+						break;
+					}
+				}
+				if current.is_some() {
+					break;
+				}
+			}
+			let mut current = current.unwrap();
+			orient_tile(&mut current, Side::Left, side_to_match);
+			img.set(x, y, &Some(current));
+		}
+	}
+
+	let size = 8 * side;
+	let mut image = Grid::<Pixel>::new(size, size, &Pixel::Black);
+	let mut rough_water_count = 0;
+	for t_x in 0..side {
+		let offset_x = t_x * 8;
+		for t_y in 0..side {
+			let offset_y = t_y * 8;
+			let tile = img.get(t_x, t_y).unwrap().as_ref().unwrap();
+			for x in 0..8 {
+				for y in 0..8 {
+					let pixel = tile.grid.get(x, y).unwrap();
+					image.set(x + offset_x, y + offset_y, pixel);
+					if let Pixel::Black = pixel {
+						rough_water_count += 1;
+					}
+				}
+			}
+		}
+	}
+
+	// the sea monsters all face the same way
+	let monster = sea_monster();
+	let found = monster.find_oriented(&image, Overlap::Allowed);
+	let facing = found.first().map(|found| found.transform);
+	let in_monster = found
+		.iter()
+		.filter(|found| Some(found.transform) == facing)
+		.flat_map(|found| monster.covered(found))
+		.collect::<HashSet<_>>();
+
+	rough_water_count - in_monster.len()
+}
+fn orient_tile(current: &mut Tile, side: Side, side_to_match: u16) {
+	let transform = Transform::all()
+		.find(|transform| current.transformed(*transform).get_boarder(side) == side_to_match)
+		.unwrap();
+	current.transform(transform);
+}
+fn shared_side_value(tile: &Tile, other: &Tile) -> (u16, u16) {
+	for tile_boarder in tile.all_boarders_iter() {
+		for other_boarder in other.all_boarders_iter() {
+			if *tile_boarder == *other_boarder {
+				return (*tile_boarder, Tile::reverse_bits(*tile_boarder));
+			}
+		}
+	}
+	(0, 0)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+enum Pixel {
+	#[default]
+	Black,
+	White,
+}
+use std::convert::TryFrom;
+impl TryFrom<u8> for Pixel {
+	type Error = ();
+	fn try_from(byte: u8) -> Result<Self, Self::Error> {
+		match byte {
+			b'#' => Ok(Pixel::Black),
+			b'.' => Ok(Pixel::White),
+			_ => Err(()),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+struct Tile {
+	id: u32,
+	// the pixels without the borders
+	grid: Grid<Pixel>,
+	// every pixel as parsed, before any transform
+	#[cfg(feature = "reference")]
+	pixels: Grid<Pixel>,
+	boarders: [u16; 4],
+	reversed_boarders: [u16; 4],
+}
+impl Tile {
+	fn new(id: u32, pixels: &Grid<Pixel>, boarders: &[u16; 4]) -> Tile {
+		let mut grid = Grid::new(8, 8, &Pixel::default());
+		for y in 0..8 {
+			for x in 0..8 {
+				grid[(x, y)] = pixels[(x + 1, y + 1)];
+			}
+		}
+		Tile {
+			id,
+			grid,
+			#[cfg(feature = "reference")]
+			pixels: pixels.clone(),
+			boarders: *boarders,
+			reversed_boarders: [
+				Self::reverse_bits(boarders[0]),
+				Self::reverse_bits(boarders[1]),
+				Self::reverse_bits(boarders[2]),
+				Self::reverse_bits(boarders[3]),
+			],
+		}
+	}
+	fn reverse_bits(side: u16) -> u16 {
+		side.reverse_bits() >> 6
+	}
+	fn all_boarders_iter(&self) -> impl Iterator<Item = &u16> {
+		self.boarders.iter().chain(self.reversed_boarders.iter())
+	}
+
+	fn transform(&mut self, transform: Transform) {
+		self.grid.transform(transform);
+		if transform.flipped() {
+			self.flip_boarders(FlipDirection::Vertical);
+		}
+		for _ in 0..transform.turns() {
+			self.rotate_boarders();
+		}
+	}
+	fn transformed(&self, transform: Transform) -> Tile {
+		let mut tile = self.clone();
+		tile.transform(transform);
+		tile
+	}
+	fn rotate_boarders(&mut self) {
+		self.boarders.rotate_right(1);
+		self.reversed_boarders.rotate_right(1);
+		std::mem::swap(&mut self.boarders[0], &mut self.reversed_boarders[0]);
+		std::mem::swap(&mut self.boarders[2], &mut self.reversed_boarders[2]);
+	}
+	fn flip_boarders(&mut self, direction: FlipDirection) {
+		match direction {
+			FlipDirection::Horizontal => {
+				self.boarders.swap(0, 2);
+				self.reversed_boarders.swap(0, 2);
+				std::mem::swap(&mut self.boarders[1], &mut self.reversed_boarders[1]);
+				std::mem::swap(&mut self.boarders[3], &mut self.reversed_boarders[3]);
+			}
+			FlipDirection::Vertical => {
+				self.boarders.swap(1, 3);
+				self.reversed_boarders.swap(1, 3);
+				std::mem::swap(&mut self.boarders[0], &mut self.reversed_boarders[0]);
+				std::mem::swap(&mut self.boarders[2], &mut self.reversed_boarders[2]);
+			}
+		}
+	}
+
+	fn get_boarder(&self, side: Side) -> u16 {
+		match side {
+			Side::Top => self.boarders[0],
+			Side::Right => self.boarders[1],
+			Side::Bottom => self.boarders[2],
+			Side::Left => self.boarders[3],
+		}
+	}
+}
+impl Ord for Tile {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.id.cmp(&other.id)
+	}
+}
+impl PartialOrd for Tile {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+impl PartialEq for Tile {
+	fn eq(&self, other: &Self) -> bool {
+		self.id == other.id
+	}
+}
+impl Eq for Tile {}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 28057939502729usize);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 2489);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
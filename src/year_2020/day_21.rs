@@ -0,0 +1,353 @@
+// File: year_2020/day_21.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::{parse_error::ParseError, *};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+pub struct Challenge {
+	products: Vec<Product>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[Example::new(
+		"example",
+		Some("5"),
+		Some("mxmxvkd,sqjhc,fvjkl"),
+	)];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		21
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let products = parse_input(input)?;
+		Ok(Self { products })
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, _>(input, input.lines().map(|line| parse_product(input, line)))
+	}
+	fn part_1(&self) -> Answer {
+		let unsafe_ingredients: HashSet<&str> = self
+			.possible_ingredients_for_allergens()
+			.into_values()
+			.flatten()
+			.collect();
+
+		self.products
+			.iter()
+			.map(|product| {
+				product
+					.ingredients
+					.iter()
+					.filter(|ingredient| !unsafe_ingredients.contains(ingredient.as_str()))
+					.count()
+			})
+			.sum::<usize>()
+			.into()
+	}
+	fn part_2(&self) -> Answer {
+		// An allergen with one possible ingredient is matched to it and the ingredient is
+		// removed from the others. Allergens that are never left with one are ambiguous.
+		let mut possible_ingredients_for_allergens = self.possible_ingredients_for_allergens();
+		let mut ingredient_allergen_pairs = Vec::<(&str, &str)>::new();
+		while let Some((&allergen, ingredients)) = possible_ingredients_for_allergens
+			.iter()
+			.find(|(_, ingredients)| ingredients.len() == 1)
+		{
+			let ingredient = *ingredients.iter().next().unwrap();
+			possible_ingredients_for_allergens.remove(allergen);
+			for ingredients in possible_ingredients_for_allergens.values_mut() {
+				ingredients.remove(ingredient);
+			}
+			ingredient_allergen_pairs.push((allergen, ingredient));
+		}
+		if !possible_ingredients_for_allergens.is_empty() {
+			return Answer::NotApplicable;
+		}
+		ingredient_allergen_pairs.sort_unstable();
+
+		ingredient_allergen_pairs
+			.iter()
+			.map(|(_, ingredient)| *ingredient)
+			.collect::<Vec<_>>()
+			.join(",")
+			.into()
+	}
+	// Every allergen is in exactly one ingredient and foods are added until each allergen
+	// can be matched to its ingredient by elimination. Now and then two allergens are always
+	// listed together instead so either could be in either ingredient.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let mut allergens = ALLERGENS.to_vec();
+		rng.shuffle(&mut allergens);
+		allergens.truncate(rng.range(1..=ALLERGENS.len()));
+		let ambiguous = allergens.len() > 1 && rng.chance(10);
+
+		let mut words = HashSet::new();
+		let mut ingredients = Vec::new();
+		while ingredients.len() < allergens.len() + size.clamp(3, 50) {
+			let word = rng.word(3..=8);
+			if words.insert(word.clone()) {
+				ingredients.push(word);
+			}
+		}
+
+		// the ingredient with allergen i is ingredients[i]
+		let mut candidates = vec![None::<HashSet<usize>>; allergens.len()];
+		let mut foods = Vec::new();
+		while foods.len() < size.max(1)
+			|| !(resolves(&candidates) || ambiguous && candidates.iter().all(Option::is_some))
+		{
+			let forced = rng.below(allergens.len());
+			let mut contains = (0..allergens.len())
+				.filter(|i| *i == forced || rng.chance(30))
+				.collect::<Vec<_>>();
+			// the first two allergens are the ones listed together
+			if ambiguous && contains.iter().any(|i| *i < 2) {
+				contains = (0..allergens.len())
+					.filter(|i| *i < 2 || contains.contains(i))
+					.collect();
+			}
+			let mut food = (0..ingredients.len())
+				.filter(|i| {
+					contains.contains(i) || rng.chance(if *i < allergens.len() { 30 } else { 40 })
+				})
+				.collect::<HashSet<_>>();
+			for i in contains.iter() {
+				candidates[*i] = Some(match candidates[*i].take() {
+					Some(possible) => possible.intersection(&food).copied().collect(),
+					None => food.clone(),
+				});
+			}
+			let mut listed = food
+				.drain()
+				.map(|i| ingredients[i].as_str())
+				.collect::<Vec<_>>();
+			rng.shuffle(&mut listed);
+			let contains = contains.iter().map(|i| allergens[*i]).collect::<Vec<_>>();
+			foods.push(format!(
+				"{} (contains {})\n",
+				listed.join(" "),
+				contains.join(", ")
+			));
+		}
+		foods.concat()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		// an ingredient is safe when every allergen is listed by a food without it
+		let safe = |ingredient: &String| {
+			self.products
+				.iter()
+				.flat_map(|product| product.allergens.iter())
+				.all(|allergen| {
+					self.products.iter().any(|product| {
+						product.allergens.contains(allergen)
+							&& !product.ingredients.contains(ingredient)
+					})
+				})
+		};
+		self.products
+			.iter()
+			.flat_map(|product| product.ingredients.iter())
+			.filter(|ingredient| safe(ingredient))
+			.count()
+			.into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		let mut allergens = self
+			.products
+			.iter()
+			.flat_map(|product| product.allergens.iter().map(String::as_str))
+			.collect::<Vec<_>>();
+		allergens.sort_unstable();
+		allergens.dedup();
+		let mut matched = Vec::with_capacity(allergens.len());
+		let mut found = Vec::new();
+		self.reference_match(&allergens, &mut matched, &mut found);
+		// more than one way to match them is ambiguous
+		match found.len() {
+			1 => found[0].join(",").into(),
+			_ => Answer::NotApplicable,
+		}
+	}
+}
+
+#[cfg(feature = "reference")]
+impl Challenge {
+	// Tries every ingredient that is in every food listing the next allergen and is not
+	// matched yet, backtracking until a second complete match is found.
+	fn reference_match<'a>(
+		&'a self,
+		allergens: &[&str],
+		matched: &mut Vec<&'a str>,
+		found: &mut Vec<Vec<&'a str>>,
+	) {
+		let allergen = match allergens.get(matched.len()) {
+			Some(allergen) => *allergen,
+			None => {
+				found.push(matched.clone());
+				return;
+			}
+		};
+		let listing = self
+			.products
+			.iter()
+			.filter(|product| product.allergens.contains(allergen))
+			.collect::<Vec<_>>();
+		for ingredient in listing[0].ingredients.iter() {
+			if !matched.contains(&ingredient.as_str())
+				&& listing
+					.iter()
+					.all(|product| product.ingredients.contains(ingredient))
+			{
+				matched.push(ingredient);
+				self.reference_match(allergens, matched, found);
+				matched.pop();
+				if found.len() > 1 {
+					return;
+				}
+			}
+		}
+	}
+}
+
+const ALLERGENS: [&str; 8] = [
+	"dairy", "eggs", "fish", "nuts", "peanuts", "sesame", "soy", "wheat",
+];
+fn resolves(candidates: &[Option<HashSet<usize>>]) -> bool {
+	let mut candidates = match candidates.iter().cloned().collect::<Option<Vec<_>>>() {
+		Some(candidates) => candidates,
+		None => return false,
+	};
+	let mut known = 0;
+	loop {
+		let found = candidates
+			.iter()
+			.filter(|possible| possible.len() == 1)
+			.flatten()
+			.copied()
+			.collect::<HashSet<_>>();
+		if found.len() == known {
+			break;
+		}
+		known = found.len();
+		for possible in candidates.iter_mut().filter(|possible| possible.len() > 1) {
+			possible.retain(|i| !found.contains(i));
+		}
+	}
+	candidates.iter().all(|possible| possible.len() == 1)
+}
+impl Challenge {
+	fn possible_ingredients_for_allergens(&self) -> HashMap<&str, HashSet<&str>> {
+		let mut possible_ingredients_for_allergens = HashMap::<&str, HashSet<&str>>::new();
+		for product in self.products.iter() {
+			let product_ingredients = product
+				.ingredients
+				.iter()
+				.map(String::as_str)
+				.collect::<HashSet<&str>>();
+			for allergen in product.allergens.iter() {
+				let ingredients = match possible_ingredients_for_allergens.get(allergen.as_str()) {
+					Some(already_added) => product_ingredients
+						.intersection(already_added)
+						.cloned()
+						.collect(),
+					None => product_ingredients.clone(),
+				};
+				possible_ingredients_for_allergens.insert(allergen, ingredients);
+			}
+		}
+		possible_ingredients_for_allergens
+	}
+}
+
+fn parse_input(input: &str) -> Result<Vec<Product>, ParseError> {
+	input
+		.lines()
+		.map(|line| parse_product(input, line))
+		.collect()
+}
+fn parse_product(input: &str, line: &str) -> Result<Product, ParseError> {
+	let (ingredients, allergens) = line
+		.strip_suffix(')')
+		.and_then(|line| line.split_once(" (contains "))
+		.ok_or_else(|| {
+			ParseError::at(
+				Challenge::year(),
+				Challenge::day(),
+				input,
+				line,
+				"expected \"<ingredients> (contains <allergens>)\"",
+			)
+		})?;
+	Ok(Product {
+		ingredients: ingredients
+			.split_ascii_whitespace()
+			.map(str::to_string)
+			.collect(),
+		allergens: allergens.split(", ").map(str::to_string).collect(),
+	})
+}
+
+struct Product {
+	ingredients: HashSet<String>,
+	allergens: HashSet<String>,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 2280);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(
+			Challenge::new().unwrap().part_2(),
+			"vfvvnm,bvgm,rdksxt,xknb,hxntcz,bktzrz,srzqtccv,gbtmdb"
+		);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
@@ -0,0 +1,325 @@
+// File: year_2020/day_22.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::{
+	parse_error::{parse_number, ParseError},
+	*,
+};
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::str::Lines;
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+pub struct Challenge {
+	deck_1: VecDeque<u8>,
+	deck_2: VecDeque<u8>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[Example::new("example", Some("306"), Some("291"))];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		22
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let (deck_1, deck_2) = parse_input(input)?;
+		Ok(Self { deck_1, deck_2 })
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		let mut parts = input.split("\n\n");
+		let cards = ["Player 1", "Player 2"]
+			.iter()
+			.flat_map(|player| match card_lines(input, parts.next(), player) {
+				Ok(lines) => lines
+					.map(|line| {
+						parse_number::<u8>(Self::year(), Self::day(), input, line).map(drop)
+					})
+					.collect(),
+				Err(e) => vec![Err(e)],
+			})
+			.collect::<Vec<_>>();
+		lint::each::<Self, _>(input, cards)
+	}
+	fn part_1(&self) -> Answer {
+		let mut player_1_deck = self.deck_1.clone();
+		let mut player_2_deck = self.deck_2.clone();
+		combat(&mut player_1_deck, &mut player_2_deck);
+		calculate_winners_score(&player_1_deck, &player_2_deck).into()
+	}
+	fn part_2(&self) -> Answer {
+		let mut player_1_deck = self.deck_1.clone();
+		let mut player_2_deck = self.deck_2.clone();
+		recursive_combat(&mut player_1_deck, &mut player_2_deck);
+		calculate_winners_score(&player_1_deck, &player_2_deck).into()
+	}
+	// The cards 1 to 2n shuffled and dealt again whenever plain combat would never end
+	// since the first part has no rule to stop it.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let n = size.clamp(1, 15) as u8;
+		loop {
+			let mut cards = (1..=2 * n).collect::<Vec<_>>();
+			rng.shuffle(&mut cards);
+			let deck_1 = cards[..n as usize].iter().copied().collect::<VecDeque<_>>();
+			let deck_2 = cards[n as usize..].iter().copied().collect::<VecDeque<_>>();
+			if combat_ends(deck_1.clone(), deck_2.clone()) {
+				let deck = |deck: VecDeque<u8>| {
+					deck.iter()
+						.map(|card| format!("\n{}", card))
+						.collect::<String>()
+				};
+				return format!("Player 1:{}\n\nPlayer 2:{}\n", deck(deck_1), deck(deck_2));
+			}
+		}
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		let mut player_1_deck = self.deck_1.iter().copied().collect::<Vec<_>>();
+		let mut player_2_deck = self.deck_2.iter().copied().collect::<Vec<_>>();
+		while !player_1_deck.is_empty() && !player_2_deck.is_empty() {
+			let card_1 = player_1_deck.remove(0);
+			let card_2 = player_2_deck.remove(0);
+			if card_1 > card_2 {
+				player_1_deck.extend([card_1, card_2].iter());
+			} else {
+				player_2_deck.extend([card_2, card_1].iter());
+			}
+		}
+		reference_score(&player_1_deck, &player_2_deck).into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		let mut player_1_deck = self.deck_1.iter().copied().collect::<Vec<_>>();
+		let mut player_2_deck = self.deck_2.iter().copied().collect::<Vec<_>>();
+		reference_recursive_combat(&mut player_1_deck, &mut player_2_deck);
+		reference_score(&player_1_deck, &player_2_deck).into()
+	}
+}
+
+// Remembers every round's decks, not a hash of them.
+#[cfg(feature = "reference")]
+fn reference_recursive_combat(player_1_deck: &mut Vec<u8>, player_2_deck: &mut Vec<u8>) -> bool {
+	let mut previous_rounds = HashSet::new();
+	while !player_1_deck.is_empty() && !player_2_deck.is_empty() {
+		if !previous_rounds.insert((player_1_deck.clone(), player_2_deck.clone())) {
+			return true;
+		}
+		let card_1 = player_1_deck.remove(0);
+		let card_2 = player_2_deck.remove(0);
+		let player_1_wins =
+			if card_1 as usize <= player_1_deck.len() && card_2 as usize <= player_2_deck.len() {
+				reference_recursive_combat(
+					&mut player_1_deck[..card_1 as usize].to_vec(),
+					&mut player_2_deck[..card_2 as usize].to_vec(),
+				)
+			} else {
+				card_1 > card_2
+			};
+		if player_1_wins {
+			player_1_deck.extend([card_1, card_2].iter());
+		} else {
+			player_2_deck.extend([card_2, card_1].iter());
+		}
+	}
+	!player_1_deck.is_empty()
+}
+#[cfg(feature = "reference")]
+fn reference_score(player_1_deck: &[u8], player_2_deck: &[u8]) -> usize {
+	let winning_deck = player_1_deck.iter().chain(player_2_deck.iter());
+	let len = player_1_deck.len() + player_2_deck.len();
+	winning_deck
+		.enumerate()
+		.map(|(i, card)| (len - i) * *card as usize)
+		.sum()
+}
+
+fn combat_ends(mut player_1_deck: VecDeque<u8>, mut player_2_deck: VecDeque<u8>) -> bool {
+	let mut seen = HashSet::new();
+	while !player_1_deck.is_empty() && !player_2_deck.is_empty() {
+		if !seen.insert((player_1_deck.clone(), player_2_deck.clone())) {
+			return false;
+		}
+		let card_1 = player_1_deck.pop_front().unwrap();
+		let card_2 = player_2_deck.pop_front().unwrap();
+		if card_1 > card_2 {
+			player_1_deck.extend([card_1, card_2].iter());
+		} else {
+			player_2_deck.extend([card_2, card_1].iter());
+		}
+	}
+	true
+}
+
+fn parse_input(input: &str) -> Result<(VecDeque<u8>, VecDeque<u8>), ParseError> {
+	let mut parts = input.split("\n\n");
+	let deck_1 = parse_deck(input, parts.next(), "Player 1")?;
+	let deck_2 = parse_deck(input, parts.next(), "Player 2")?;
+	Ok((deck_1, deck_2))
+}
+fn parse_deck(input: &str, part: Option<&str>, player: &str) -> Result<VecDeque<u8>, ParseError> {
+	card_lines(input, part, player)?
+		.map(|line| parse_number(Challenge::year(), Challenge::day(), input, line))
+		.collect()
+}
+// The lines of the cards after the player's header.
+fn card_lines<'a>(
+	input: &str,
+	part: Option<&'a str>,
+	player: &str,
+) -> Result<Lines<'a>, ParseError> {
+	let year = Challenge::year();
+	let day = Challenge::day();
+	let part = part.ok_or_else(|| {
+		ParseError::at(
+			year,
+			day,
+			input,
+			&input[input.len()..],
+			format!("expected a deck for {}", player),
+		)
+	})?;
+	let mut lines = part.lines();
+	let header = lines.next().unwrap_or(part);
+	if header.strip_suffix(':') != Some(player) {
+		return Err(ParseError::at(
+			year,
+			day,
+			input,
+			header,
+			format!("expected \"{}:\"", player),
+		));
+	}
+	Ok(lines)
+}
+fn combat(player_1_deck: &mut VecDeque<u8>, player_2_deck: &mut VecDeque<u8>) {
+	while !player_1_deck.is_empty() && !player_2_deck.is_empty() {
+		if player_1_deck[0] > player_2_deck[0] {
+			player_1_deck.rotate_left(1);
+			player_1_deck.push_back(player_2_deck.pop_front().unwrap());
+		} else {
+			player_2_deck.rotate_left(1);
+			player_2_deck.push_back(player_1_deck.pop_front().unwrap());
+		}
+	}
+}
+fn recursive_combat(player_1_deck: &mut VecDeque<u8>, player_2_deck: &mut VecDeque<u8>) -> bool {
+	let mut previous_rounds = HashSet::new();
+	while !player_1_deck.is_empty() && !player_2_deck.is_empty() {
+		let mut hasher = DefaultHasher::new();
+		player_1_deck.hash(&mut hasher);
+		player_2_deck.hash(&mut hasher);
+		let hash = hasher.finish();
+		if previous_rounds.contains(&hash) {
+			return true;
+		}
+		previous_rounds.insert(hash);
+
+		let player_1_card = player_1_deck.pop_front().unwrap();
+		let player_2_card = player_2_deck.pop_front().unwrap();
+
+		let player_1_wins = if player_1_card as usize <= player_1_deck.len()
+			&& player_2_card as usize <= player_2_deck.len()
+		{
+			let mut new_player_1_deck = player_1_deck
+				.iter()
+				.take(player_1_card as usize)
+				.copied()
+				.collect::<VecDeque<_>>();
+			let mut new_player_2_deck = player_2_deck
+				.iter()
+				.take(player_2_card as usize)
+				.copied()
+				.collect::<VecDeque<_>>();
+			recursive_combat(&mut new_player_1_deck, &mut new_player_2_deck)
+		} else {
+			player_1_card > player_2_card
+		};
+
+		if player_1_wins {
+			player_1_deck.push_back(player_1_card);
+			player_1_deck.push_back(player_2_card);
+		} else {
+			player_2_deck.push_back(player_2_card);
+			player_2_deck.push_back(player_1_card);
+		}
+	}
+	!player_1_deck.is_empty()
+}
+fn calculate_winners_score(player_1_deck: &VecDeque<u8>, player_2_deck: &VecDeque<u8>) -> usize {
+	let winning_deck = if !player_1_deck.is_empty() {
+		player_1_deck
+	} else {
+		player_2_deck
+	};
+	winning_deck
+		.iter()
+		.rev()
+		.enumerate()
+		.fold(0, |sum, (i, &card)| sum + (i + 1) * card as usize)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 32083);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 35495);
+	}
+	#[test]
+	fn lint_test() {
+		assert!(Challenge::lint(&Challenge::read_input().unwrap()).is_empty());
+		let problems = Challenge::lint("Player 1:\n9\n2\nx\n\nPlayer Two:\n5\n");
+		let positions = problems
+			.iter()
+			.map(|problem| (problem.line, problem.column))
+			.collect::<Vec<_>>();
+		assert_eq!(positions, [(4, 1), (6, 1)]);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
@@ -1,4 +1,4 @@
-// File: day_23.rs
+// File: year_2020/day_23.rs
 // Author: Jacob Guenther
 // Date: December 2020
 
@@ -23,25 +23,57 @@ IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
 
-use super::common::*;
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::registry::Part;
+use crate::common::{parse_error::ParseError, *};
+#[cfg(feature = "reference")]
+use std::collections::HashMap;
 use std::collections::VecDeque;
 
 pub struct Challenge {
-	part_1_result: usize,
-	part_2_result: usize,
+	labels: Vec<u32>,
 }
 impl ChallengeT for Challenge {
-	type Output1 = usize;
-	type Output2 = usize;
+	const EXAMPLES: &'static [Example] = &[Example::new(
+		"example",
+		Some("67384529"),
+		Some("149245887792"),
+	)];
+	// ten million moves are too slow to make on every generated input
+	const GENERATED_PARTS: &'static [Part] = &[Part::One];
+	#[cfg(feature = "reference")]
+	const REFERENCE_PARTS: &'static [Part] = &[Part::One];
 
+	fn year() -> u16 {
+		super::YEAR
+	}
 	fn day() -> u8 {
 		23
 	}
-	fn new() -> Self {
-		let input = [3, 6, 8, 1, 9, 5, 7, 4, 2];
-
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let labels = input.trim();
+		let mut sorted = labels.chars().collect::<Vec<_>>();
+		sorted.sort_unstable();
+		if sorted.iter().collect::<String>() != "123456789" {
+			return Err(ParseError::at(
+				Self::year(),
+				Self::day(),
+				input,
+				labels,
+				"expected the cups 1 to 9 each labeled once",
+			));
+		}
+		let labels = labels
+			.bytes()
+			.map(|b| (b - b'0') as u32)
+			.collect::<Vec<_>>();
+		Ok(Self { labels })
+	}
+	fn part_1(&self) -> Answer {
 		let mut cups = VecDeque::with_capacity(10);
-		for label in input.iter() {
+		for label in self.labels.iter() {
 			cups.push_back(*label);
 		}
 
@@ -58,10 +90,14 @@ impl ChallengeT for Challenge {
 			part_1_result *= 10;
 			part_1_result += cup as usize;
 		}
+		part_1_result.into()
+	}
+	fn part_2(&self) -> Answer {
+		let input = &self.labels;
 
 		// index is cup, value is next cup
 		// ignore index 0
-		let mut cups = (1..(1_000_002)).collect::<Vec<u32>>();
+		let mut cups = (1..1_000_002).collect::<Vec<u32>>();
 		for (&current, &next) in input.iter().zip(input[1..].iter()) {
 			cups[current as usize] = next;
 		}
@@ -77,18 +113,69 @@ impl ChallengeT for Challenge {
 
 		let second = cups[1];
 		let third = cups[second as usize];
-		let part_2_result = second as usize * third as usize;
-		Self {
-			part_1_result,
-			part_2_result,
+		(second as usize * third as usize).into()
+	}
+	// The cups are always 1 to 9 so only their order is random.
+	fn generate(rng: &mut Rng, _size: usize) -> String {
+		let mut labels = (1..=9).collect::<Vec<u8>>();
+		rng.shuffle(&mut labels);
+		format!("{}\n", labels.iter().map(u8::to_string).collect::<String>())
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		let next = reference_play(&self.labels, 9, 100);
+		let mut labels = String::new();
+		let mut cup = next[&1];
+		while cup != 1 {
+			labels.push_str(&cup.to_string());
+			cup = next[&cup];
 		}
+		labels.parse::<u64>().unwrap().into()
 	}
-	fn part_1(&self) -> Self::Output1 {
-		self.part_1_result
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		let next = reference_play(&self.labels, 1_000_000, 10_000_000);
+		(next[&1] as u64 * next[&next[&1]] as u64).into()
 	}
-	fn part_2(&self) -> Self::Output2 {
-		self.part_2_result
+}
+
+// The cup clockwise of each cup after the moves, the cups after the labeled ones go up
+// from 10 to the number of cups.
+#[cfg(feature = "reference")]
+fn reference_play(labels: &[u32], cups: u32, moves: usize) -> HashMap<u32, u32> {
+	let order = labels
+		.iter()
+		.copied()
+		.chain((labels.len() as u32 + 1)..=cups)
+		.collect::<Vec<_>>();
+	let mut next = HashMap::new();
+	for (i, cup) in order.iter().enumerate() {
+		next.insert(*cup, order[(i + 1) % order.len()]);
+	}
+	let mut current = order[0];
+	for _ in 0..moves {
+		let picked_up = [
+			next[&current],
+			next[&next[&current]],
+			next[&next[&next[&current]]],
+		];
+		let mut destination = current;
+		loop {
+			destination = if destination == 1 {
+				cups
+			} else {
+				destination - 1
+			};
+			if !picked_up.contains(&destination) {
+				break;
+			}
+		}
+		next.insert(current, next[&picked_up[2]]);
+		next.insert(picked_up[2], next[&destination]);
+		next.insert(destination, picked_up[0]);
+		current = next[&current];
 	}
+	next
 }
 
 fn do_move(cups: &mut VecDeque<u32>, max: u32) {
@@ -121,7 +208,7 @@ fn do_move(cups: &mut VecDeque<u32>, max: u32) {
 	cups.insert(destination_index + 1, picked_up_1);
 }
 
-fn do_move_2(cups: &mut Vec<u32>, current: usize, max: usize) -> u32 {
+fn do_move_2(cups: &mut [u32], current: usize, max: usize) -> u32 {
 	let picked_up_1 = cups[current];
 	let picked_up_2 = cups[picked_up_1 as usize];
 	let picked_up_3 = cups[picked_up_2 as usize];
@@ -152,21 +239,23 @@ fn do_move_2(cups: &mut Vec<u32>, current: usize, max: usize) -> u32 {
 mod tests {
 	use super::Challenge;
 	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
 	use test::Bencher;
 
 	#[test]
 	fn part_1_test() {
-		assert_eq!(Challenge::new().part_1(), 95648732);
+		assert_eq!(Challenge::new().unwrap().part_1(), 95648732);
 	}
 	#[test]
 	fn part_2_test() {
-		assert_eq!(Challenge::new().part_2(), 192515314252);
+		assert_eq!(Challenge::new().unwrap().part_2(), 192515314252usize);
 	}
 
+	#[cfg(feature = "nightly")]
 	#[bench]
 	fn both(b: &mut Bencher) {
 		b.iter(|| {
-			let challenge = Challenge::new();
+			let challenge = Challenge::new().unwrap();
 			challenge.part_1();
 			challenge.part_2();
 		})
@@ -0,0 +1,292 @@
+// File: year_2020/day_24.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::registry::Part;
+use crate::common::{parse_error::ParseError, vec3::Vec3, *};
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::slice::Iter;
+
+type Coord = Vec3<i16>;
+type FlippedTiles = HashSet<Coord>;
+
+pub struct Challenge {
+	paths: Vec<Vec<HexDirection>>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[Example::new("example", Some("10"), Some("2208"))];
+	// part 2 takes too long to run on every generated input, with its reference solution
+	// even in a release build
+	const GENERATED_PARTS: &'static [Part] = &[Part::One];
+	#[cfg(feature = "reference")]
+	const REFERENCE_PARTS: &'static [Part] = &[Part::One];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		24
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let paths = parse_input(input)?;
+		Ok(Self { paths })
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, _>(input, input.lines().map(|line| parse_path(input, line)))
+	}
+	fn part_1(&self) -> Answer {
+		init_floor(&self.paths).len().into()
+	}
+	fn part_2(&self) -> Answer {
+		game_of_life(&init_floor(&self.paths)).into()
+	}
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		const DIRECTIONS: [&str; 6] = ["e", "se", "sw", "w", "nw", "ne"];
+		(0..size.max(1))
+			.map(|_| {
+				let steps = rng.range(1..=20);
+				let mut line = (0..steps)
+					.map(|_| *rng.choose(&DIRECTIONS))
+					.collect::<String>();
+				line.push('\n');
+				line
+			})
+			.collect()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		reference_flip(&self.paths).len().into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		let mut black = reference_flip(&self.paths);
+		for _ in 0..100 {
+			let neighbours =
+				|(x, y): (i32, i32)| REFERENCE_STEPS.iter().map(move |(dx, dy)| (x + dx, y + dy));
+			let candidates = black
+				.iter()
+				.flat_map(|tile| neighbours(*tile).chain(Some(*tile)))
+				.collect::<HashSet<_>>();
+			black = candidates
+				.into_iter()
+				.filter(|tile| {
+					let adjacent = neighbours(*tile).filter(|n| black.contains(n)).count();
+					adjacent == 2 || (adjacent == 1 && black.contains(tile))
+				})
+				.collect();
+		}
+		black.len().into()
+	}
+}
+
+// Steps in "doubled" coordinates where east and west move 2 and the diagonals move 1
+// each way, in the same order as HexDirection.
+#[cfg(feature = "reference")]
+const REFERENCE_STEPS: [(i32, i32); 6] = [(2, 0), (1, -1), (-1, -1), (-2, 0), (-1, 1), (1, 1)];
+// The tiles left black after following every path from the reference tile.
+#[cfg(feature = "reference")]
+fn reference_flip(paths: &[Vec<HexDirection>]) -> HashSet<(i32, i32)> {
+	let mut black = HashSet::new();
+	for path in paths.iter() {
+		let (mut x, mut y) = (0, 0);
+		for direction in path.iter() {
+			let (dx, dy) = REFERENCE_STEPS[*direction as usize];
+			x += dx;
+			y += dy;
+		}
+		if !black.insert((x, y)) {
+			black.remove(&(x, y));
+		}
+	}
+	black
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<HexDirection>>, ParseError> {
+	input.lines().map(|line| parse_path(input, line)).collect()
+}
+fn parse_path(input: &str, line: &str) -> Result<Vec<HexDirection>, ParseError> {
+	let east = b'e';
+	let south = b's';
+	let west = b'w';
+	let north = b'n';
+	let bytes = line.as_bytes();
+	let mut path = Vec::new();
+	let mut i = 0;
+	while i < line.len() {
+		let start = i;
+		let unsupported = |len: usize| {
+			let direction = line[start..].chars().take(len).collect::<String>();
+			ParseError::at(
+				Challenge::year(),
+				Challenge::day(),
+				input,
+				&line[start..],
+				format!("{} not a supported direction", direction),
+			)
+		};
+		let direction = match bytes[i] {
+			b1 if b1 == east => {
+				i += 1;
+				HexDirection::East
+			}
+			b1 if b1 == south => {
+				i += 2;
+				match bytes.get(i - 1) {
+					Some(&b2) if b2 == east => HexDirection::SouthEast,
+					Some(&b2) if b2 == west => HexDirection::SouthWest,
+					_ => return Err(unsupported(2)),
+				}
+			}
+			b1 if b1 == west => {
+				i += 1;
+				HexDirection::West
+			}
+			b1 if b1 == north => {
+				i += 2;
+				match bytes.get(i - 1) {
+					Some(&b2) if b2 == west => HexDirection::NorthWest,
+					Some(&b2) if b2 == east => HexDirection::NorthEast,
+					_ => return Err(unsupported(2)),
+				}
+			}
+			_ => return Err(unsupported(1)),
+		};
+		path.push(direction);
+	}
+	Ok(path)
+}
+fn init_floor(paths: &[Vec<HexDirection>]) -> FlippedTiles {
+	let mut floor = FlippedTiles::new();
+	for path in paths.iter() {
+		let mut coord = Coord::new(0, 0, 0);
+		for direction in path.iter() {
+			let offset = direction.into();
+			coord.add_other(&offset);
+		}
+		if !floor.insert(coord) {
+			floor.remove(&coord);
+		}
+	}
+	floor
+}
+fn game_of_life(floor: &FlippedTiles) -> usize {
+	let mut floor = floor.clone();
+	for _ in 0..100 {
+		let mut adjacent_counts = HashMap::<Coord, u8>::new();
+		for coord in floor.iter() {
+			for direction in HexDirection::iterator() {
+				let offset = direction.into();
+				let mut adjacent = *coord;
+				adjacent.add_other(&offset);
+				let entry = adjacent_counts.entry(adjacent).or_insert(0);
+				*entry += 1;
+			}
+		}
+		floor = adjacent_counts
+			.iter()
+			.filter_map(|(coord, count)| {
+				if *count == 2 || (*count == 1 && floor.contains(coord)) {
+					Some(coord)
+				} else {
+					None
+				}
+			})
+			.copied()
+			.collect();
+	}
+	floor.len()
+}
+
+#[derive(Debug, Copy, Clone)]
+enum HexDirection {
+	East,
+	SouthEast,
+	SouthWest,
+	West,
+	NorthWest,
+	NorthEast,
+}
+impl HexDirection {
+	pub fn iterator() -> Iter<'static, HexDirection> {
+		static DIRECTIONS: [HexDirection; 6] = [
+			HexDirection::East,
+			HexDirection::SouthEast,
+			HexDirection::SouthWest,
+			HexDirection::West,
+			HexDirection::NorthWest,
+			HexDirection::NorthEast,
+		];
+		DIRECTIONS.iter()
+	}
+}
+impl From<&HexDirection> for Vec3<i16> {
+	fn from(dir: &HexDirection) -> Vec3<i16> {
+		match dir {
+			HexDirection::East => Vec3::new(1, -1, 0),
+			HexDirection::SouthEast => Vec3::new(0, -1, 1),
+			HexDirection::SouthWest => Vec3::new(-1, 0, 1),
+			HexDirection::West => Vec3::new(-1, 1, 0),
+			HexDirection::NorthWest => Vec3::new(0, 1, -1),
+			HexDirection::NorthEast => Vec3::new(1, 0, -1),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 512);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 4120);
+	}
+	#[test]
+	fn parse_error_test() {
+		let error = Challenge::from_input("esew\nnwwswee\nsenwsx")
+			.err()
+			.unwrap();
+		assert_eq!((error.day, error.line, error.column), (24, 3, 5));
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
@@ -0,0 +1,158 @@
+// File: year_2020/day_25.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::{
+	parse_error::{parse_number, ParseError},
+	*,
+};
+
+pub struct Challenge {
+	public_key_1: usize,
+	public_key_2: usize,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[Example::new("example", Some("14897079"), Some("N/A"))];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		25
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let public_keys = input
+			.lines()
+			.map(|line| parse_public_key(input, line))
+			.collect::<Result<Vec<_>, _>>()?;
+		let (public_key_1, public_key_2) = match public_keys[..] {
+			[public_key_1, public_key_2] => (public_key_1, public_key_2),
+			_ => {
+				return Err(ParseError::new(
+					Self::year(),
+					Self::day(),
+					0,
+					0,
+					format!("expected 2 public keys but found {}", public_keys.len()),
+				))
+			}
+		};
+		Ok(Self {
+			public_key_1,
+			public_key_2,
+		})
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, _>(
+			input,
+			input.lines().map(|line| parse_public_key(input, line)),
+		)
+	}
+	fn part_1(&self) -> Answer {
+		let mut loop_size = 0;
+		let mut result = 1;
+
+		// faster to find secret key 2 first
+		while result != self.public_key_2 {
+			loop_size += 1;
+			result = (result * 7) % 20201227;
+		}
+
+		let mut result = 1;
+		for _ in 0..loop_size {
+			result = (result * self.public_key_1) % 20201227;
+		}
+		result.into()
+	}
+	// The last day only has one puzzle.
+	fn part_2(&self) -> Answer {
+		Answer::NotApplicable
+	}
+	// The loop sizes grow with the size so larger inputs take longer to crack.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let mut public_key = || {
+			let loop_size = rng.range(1..=size.max(1) * 10000);
+			(0..loop_size).fold(1, |key, _| key * 7 % 20201227)
+		};
+		format!("{}\n{}\n", public_key(), public_key())
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		// finds the first loop size instead of the second
+		let mut loop_size = 0;
+		let mut key = 1;
+		while key != self.public_key_1 {
+			key = key * 7 % 20201227;
+			loop_size += 1;
+		}
+		(0..loop_size)
+			.fold(1, |key, _| key * self.public_key_2 % 20201227)
+			.into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		Answer::NotApplicable
+	}
+}
+fn parse_public_key(input: &str, line: &str) -> Result<usize, ParseError> {
+	match parse_number::<usize>(Challenge::year(), Challenge::day(), input, line)? {
+		key @ 1..=20201226 => Ok(key),
+		_ => Err(ParseError::at(
+			Challenge::year(),
+			Challenge::day(),
+			input,
+			line,
+			"public keys must be between 1 and 20201226",
+		)),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Answer, Challenge};
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 17673381);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), Answer::NotApplicable);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+		})
+	}
+}
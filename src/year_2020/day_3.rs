@@ -0,0 +1,172 @@
+// File: year_2020/day_3.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::grid::Grid;
+use crate::common::lint;
+use crate::common::parse_error::ParseError;
+use crate::common::ChallengeT;
+
+type TreeMap = Grid<bool>;
+
+pub struct Challenge {
+	tree_map: TreeMap,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[Example::new("example", Some("7"), Some("336"))];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		3
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let tree_map = Grid::parse(Self::year(), Self::day(), input, parse_square)?;
+		if tree_map.data.is_empty() {
+			return Err(ParseError::new(
+				Self::year(),
+				Self::day(),
+				1,
+				1,
+				"expected a map",
+			));
+		}
+		Ok(Self { tree_map })
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		let problems = Grid::problems(Self::year(), Self::day(), input, parse_square);
+		lint::each::<Self, _>(input, problems.into_iter().map(Err::<(), _>))
+	}
+	fn part_1(&self) -> Answer {
+		count_trees_hit(&self.tree_map, 3, 1).into()
+	}
+	fn part_2(&self) -> Answer {
+		let steps = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+		steps
+			.iter()
+			.map(|step| count_trees_hit(&self.tree_map, step.0, step.1))
+			.product::<usize>()
+			.into()
+	}
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let width = rng.range(1..=31);
+		(0..size.max(1))
+			.map(|_| {
+				let mut line = (0..width)
+					.map(|_| if rng.chance(20) { '#' } else { '.' })
+					.collect::<String>();
+				line.push('\n');
+				line
+			})
+			.collect()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		reference_trees_hit(&self.tree_map, 3, 1).into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		[(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+			.iter()
+			.map(|(right, down)| reference_trees_hit(&self.tree_map, *right, *down))
+			.product::<usize>()
+			.into()
+	}
+}
+
+// Walks the slope one step at a time on a map repeated as far right as it goes.
+#[cfg(feature = "reference")]
+fn reference_trees_hit(tree_map: &TreeMap, right: usize, down: usize) -> usize {
+	let (mut x, mut y) = (0, 0);
+	let mut hits = 0;
+	while y < tree_map.size.y {
+		if tree_map[(x % tree_map.size.x, y)] {
+			hits += 1;
+		}
+		x += right;
+		y += down;
+	}
+	hits
+}
+fn count_trees_hit(tree_map: &TreeMap, step_x: usize, step_y: usize) -> usize {
+	let width = tree_map.size.x;
+	tree_map
+		.data
+		.chunks(width)
+		.enumerate()
+		.filter(|(step_i, _)| step_i % step_y == 0)
+		.fold(0, |hits, (step_i, row)| {
+			let pos_x = step_i * step_x / step_y % width;
+			hits + row[pos_x] as usize
+		})
+}
+// Whether there is a tree on the square.
+fn parse_square(byte: u8) -> Result<bool, ()> {
+	match byte {
+		b'#' => Ok(true),
+		b'.' => Ok(false),
+		_ => Err(()),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 156);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 3521829480usize);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn part_1_bench(b: &mut Bencher) {
+		b.iter(|| Challenge::new().unwrap().part_1())
+	}
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn part_2_bench(b: &mut Bencher) {
+		b.iter(|| Challenge::new().unwrap().part_2())
+	}
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
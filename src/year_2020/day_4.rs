@@ -0,0 +1,315 @@
+// File: year_2020/day_4.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::lint;
+use crate::common::parse_error::ParseError;
+use crate::common::ChallengeT;
+
+type Passport = Vec<(String, String)>;
+
+pub struct Challenge {
+	passports: Vec<Passport>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[
+		Example::new("example", Some("2"), Some("2")),
+		Example::new("invalid", None, Some("0")),
+		Example::new("valid", None, Some("4")),
+	];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		4
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let passports = input
+			.split("\n\n")
+			.map(|passport| {
+				passport
+					.split_whitespace()
+					.map(|field| parse_field(input, field))
+					.collect::<Result<Passport, ParseError>>()
+			})
+			.collect::<Result<Vec<Passport>, ParseError>>()?;
+
+		Ok(Self { passports })
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, _>(
+			input,
+			input
+				.split_whitespace()
+				.map(|field| parse_field(input, field)),
+		)
+	}
+	fn part_1(&self) -> Answer {
+		self.count_valid(|passport_data, key, _| to_passport_data_1(passport_data, key))
+			.into()
+	}
+	fn part_2(&self) -> Answer {
+		self.count_valid(to_passport_data_2).into()
+	}
+	// Fields are sometimes missing or hold values that are not valid for part 2.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+		let mut passports = Vec::new();
+		for _ in 0..size.max(1) {
+			let mut fields = Vec::new();
+			for key in ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"].iter() {
+				if rng.chance(if *key == "cid" { 50 } else { 90 }) {
+					let valid = rng.chance(85);
+					let value = match (*key, valid) {
+						("byr", true) => rng.range(1920..=2002).to_string(),
+						("iyr", true) => rng.range(2010..=2020).to_string(),
+						("eyr", true) => rng.range(2020..=2030).to_string(),
+						("byr", false) | ("iyr", false) | ("eyr", false) => {
+							rng.range(1900..=2040).to_string()
+						}
+						("hgt", true) if rng.chance(50) => format!("{}cm", rng.range(150..=193)),
+						("hgt", true) => format!("{}in", rng.range(59..=76)),
+						("hgt", false) => match rng.below(3) {
+							0 => format!("{}cm", rng.range(100..=149)),
+							1 => format!("{}in", rng.range(77..=99)),
+							_ => rng.range(50..=200).to_string(),
+						},
+						("hcl", true) => format!("#{:06x}", rng.below(1 << 24)),
+						("hcl", false) => match rng.below(3) {
+							0 => format!("{:06x}", rng.below(1 << 24)),
+							1 => format!("#{:04x}", rng.below(1 << 16)),
+							_ => rng.word(1..=3),
+						},
+						("ecl", true) => rng.choose(&EYE_COLORS).to_string(),
+						("ecl", false) => rng.word(3..=3),
+						("pid", true) => format!("{:09}", rng.below(1_000_000_000)),
+						("pid", false) => format!("{:010}", rng.below(1_000_000_000)),
+						_ => rng.range(100..=350).to_string(),
+					};
+					fields.push(format!("{}:{}", key, value));
+				}
+			}
+			rng.shuffle(&mut fields);
+			let mut passport = String::new();
+			for (i, field) in fields.iter().enumerate() {
+				if i > 0 {
+					passport.push(if rng.chance(30) { '\n' } else { ' ' });
+				}
+				passport.push_str(field);
+			}
+			passports.push(passport);
+		}
+		passports.join("\n\n") + "\n"
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		self.passports
+			.iter()
+			.filter(|passport| {
+				REQUIRED
+					.iter()
+					.all(|required| passport.iter().any(|(key, _)| key == required))
+			})
+			.count()
+			.into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		self.passports
+			.iter()
+			.filter(|passport| {
+				REQUIRED.iter().all(|required| {
+					passport
+						.iter()
+						.any(|(key, value)| key == required && reference_valid(key, value))
+				})
+			})
+			.count()
+			.into()
+	}
+}
+
+#[cfg(feature = "reference")]
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+// The rules exactly as the puzzle words them.
+#[cfg(feature = "reference")]
+fn reference_valid(key: &str, value: &str) -> bool {
+	let digits =
+		|value: &str, len: usize| value.len() == len && value.bytes().all(|b| b.is_ascii_digit());
+	let between = |value: &str, len: usize, min: u32, max: u32| {
+		digits(value, len) && (min..=max).contains(&value.parse::<u32>().unwrap())
+	};
+	match key {
+		"byr" => between(value, 4, 1920, 2002),
+		"iyr" => between(value, 4, 2010, 2020),
+		"eyr" => between(value, 4, 2020, 2030),
+		"hgt" => match (value.strip_suffix("cm"), value.strip_suffix("in")) {
+			(Some(cm), _) => between(cm, 3, 150, 193),
+			(_, Some(inches)) => between(inches, 2, 59, 76),
+			_ => false,
+		},
+		"hcl" => {
+			value.len() == 7
+				&& value.starts_with('#')
+				&& value[1..]
+					.bytes()
+					.all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+		}
+		"ecl" => ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&value),
+		"pid" => digits(value, 9),
+		_ => false,
+	}
+}
+fn parse_field(input: &str, field: &str) -> Result<(String, String), ParseError> {
+	match field.split_once(':') {
+		Some((key, value)) => Ok((key.to_string(), value.to_string())),
+		None => Err(ParseError::at(
+			Challenge::year(),
+			Challenge::day(),
+			input,
+			field,
+			format!("expected \"key:value\" but found \"{}\"", field),
+		)),
+	}
+}
+impl Challenge {
+	fn count_valid(&self, to_passport_data: fn(&PassportData, &str, &str) -> PassportData) -> u16 {
+		self.passports
+			.iter()
+			.filter(|passport| {
+				passport
+					.iter()
+					.fold(PassportData::default(), |acc, (key, value)| {
+						to_passport_data(&acc, key, value)
+					})
+					.is_valid()
+			})
+			.count() as u16
+	}
+}
+fn to_passport_data_1(passport_data: &PassportData, key: &str) -> PassportData {
+	let mut new = *passport_data;
+	match key {
+		"byr" => new.birth_year = true,
+		"iyr" => new.issue_year = true,
+		"eyr" => new.experation_year = true,
+		"hgt" => new.height = true,
+		"hcl" => new.hair_color = true,
+		"ecl" => new.eye_color = true,
+		"pid" => new.passport_id = true,
+		_ => (),
+	}
+	new
+}
+fn to_passport_data_2(passport_data: &PassportData, key: &str, value: &str) -> PassportData {
+	let mut new = *passport_data;
+	match key {
+		"byr" => new.birth_year = value.parse::<u16>().is_ok_and(|v| 1919 < v && v < 2003),
+		"iyr" => new.issue_year = value.parse::<u16>().is_ok_and(|v| 2009 < v && v < 2021),
+		"eyr" => new.experation_year = value.parse::<u16>().is_ok_and(|v| 2019 < v && v < 2031),
+		"hgt" => {
+			new.height = if let Some(cm) = value.strip_suffix("cm") {
+				cm.parse::<u16>().is_ok_and(|n| 149 < n && n < 194)
+			} else if let Some(inches) = value.strip_suffix("in") {
+				inches.parse::<u16>().is_ok_and(|n| 58 < n && n < 77)
+			} else {
+				false
+			}
+		}
+		"hcl" => {
+			new.hair_color = {
+				if value.len() != 7 || value.bytes().next().unwrap() != b'#' {
+					false
+				} else {
+					u32::from_str_radix(&value[1..], 16).is_ok()
+				}
+			}
+		}
+		"ecl" => {
+			new.eye_color = matches!(value, "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth")
+		}
+		"pid" => {
+			new.passport_id = {
+				if value.len() != 9 {
+					false
+				} else {
+					value.parse::<u32>().is_ok()
+				}
+			}
+		}
+		_ => (),
+	}
+	new
+}
+#[derive(Debug, Copy, Clone, Default)]
+struct PassportData {
+	birth_year: bool,
+	issue_year: bool,
+	experation_year: bool,
+	height: bool,
+	hair_color: bool,
+	eye_color: bool,
+	passport_id: bool,
+}
+impl PassportData {
+	fn is_valid(&self) -> bool {
+		self.birth_year
+			&& self.issue_year
+			&& self.experation_year
+			&& self.height
+			&& self.hair_color
+			&& self.eye_color
+			&& self.passport_id
+	}
+}
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 235);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 194);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
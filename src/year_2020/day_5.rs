@@ -0,0 +1,197 @@
+// File: year_2020/day_5.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::lint;
+use crate::common::parse_error::ParseError;
+use crate::common::ChallengeT;
+
+pub struct Challenge {
+	// Sorted without duplicates.
+	seat_ids: Vec<usize>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[Example::new("example", Some("820"), None)];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		5
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let mut seat_ids = input
+			.lines()
+			.map(|line| get_id(input, line))
+			.collect::<Result<Vec<usize>, ParseError>>()?;
+		seat_ids.sort_unstable();
+		seat_ids.dedup();
+
+		// a seat id between the lowest and highest is missing
+		match (seat_ids.first(), seat_ids.last()) {
+			(Some(lowest), Some(highest)) if seat_ids.len() <= highest - lowest => {
+				Ok(Self { seat_ids })
+			}
+			_ => Err(ParseError::new(
+				Self::year(),
+				Self::day(),
+				0,
+				0,
+				"no empty seat between two filled seats",
+			)),
+		}
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, _>(input, input.lines().map(|line| get_id(input, line)))
+	}
+	fn part_1(&self) -> Answer {
+		(*self.seat_ids.last().unwrap()).into()
+	}
+	fn part_2(&self) -> Answer {
+		let pair = self
+			.seat_ids
+			.windows(2)
+			.find(|pair| pair[0] + 1 != pair[1])
+			.unwrap();
+		(pair[0] + 1).into()
+	}
+	// Every seat between the lowest and highest is taken except one.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let seats = size.clamp(3, 1000);
+		let lowest = rng.range(0..=(1024 - seats - 1));
+		let empty = lowest + rng.range(1..=(seats - 1));
+		let mut seat_ids = (lowest..=(lowest + seats))
+			.filter(|id| *id != empty)
+			.collect::<Vec<_>>();
+		rng.shuffle(&mut seat_ids);
+		seat_ids
+			.iter()
+			.map(|id| {
+				let mut pass = (0..10)
+					.rev()
+					.map(|bit| match (bit, id >> bit & 1) {
+						(0..=2, 0) => 'L',
+						(0..=2, _) => 'R',
+						(_, 0) => 'F',
+						_ => 'B',
+					})
+					.collect::<String>();
+				pass.push('\n');
+				pass
+			})
+			.collect()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		self.seat_ids
+			.iter()
+			.max()
+			.map_or(Answer::NotApplicable, |id| (*id).into())
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		(1..1023)
+			.find(|id| {
+				!self.seat_ids.contains(id)
+					&& self.seat_ids.contains(&(id - 1))
+					&& self.seat_ids.contains(&(id + 1))
+			})
+			.map_or(Answer::NotApplicable, Answer::from)
+	}
+}
+
+fn get_id(input: &str, line: &str) -> Result<usize, ParseError> {
+	if line.len() != 10 {
+		return Err(ParseError::at(
+			Challenge::year(),
+			Challenge::day(),
+			input,
+			line,
+			format!("expected 10 characters but found {}", line.len()),
+		));
+	}
+	line.char_indices().try_fold(0, |acc, (i, c)| {
+		let bit = match (i, c) {
+			(0..=6, 'F') | (7..=9, 'L') => 0,
+			(0..=6, 'B') | (7..=9, 'R') => 1,
+			_ => {
+				return Err(ParseError::at(
+					Challenge::year(),
+					Challenge::day(),
+					input,
+					&line[i..],
+					format!("unexpected '{}'", c),
+				))
+			}
+		};
+		Ok(acc * 2 + bit)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 974);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 646);
+	}
+	#[test]
+	fn lint_test() {
+		let problems =
+			Challenge::lint("FBFBBFFRLR\nFBFBBFXRLR\nBFFFBBFRRR\nBBFFBBFRLLX\nFFFBBBFQRR");
+		let positions = problems
+			.iter()
+			.map(|problem| (problem.line, problem.column))
+			.collect::<Vec<_>>();
+		assert_eq!(positions, [(2, 7), (4, 1), (5, 8)]);
+	}
+	#[test]
+	fn no_empty_seat_test() {
+		assert!(Challenge::from_input("FFFFFFFLLL\nFFFFFFFLRL").is_ok());
+		assert!(Challenge::from_input("FFFFFFFLLL\nFFFFFFFLLR\nFFFFFFFLRL").is_err());
+		assert!(Challenge::from_input("BBBBBBBRRR").is_err());
+		assert!(Challenge::from_input("").is_err());
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
@@ -0,0 +1,178 @@
+// File: year_2020/day_6.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use std::collections::HashSet;
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::lint;
+use crate::common::parse_error::ParseError;
+use crate::common::ChallengeT;
+
+pub struct Challenge {
+	// Every group's answers, one string for each person.
+	groups: Vec<Vec<String>>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[Example::new("example", Some("11"), Some("6"))];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		6
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		if let Some(e) = unexpected_answers(input).next() {
+			return Err(e);
+		}
+		Ok(Self {
+			groups: input
+				.split("\n\n")
+				.map(|group| group.split_whitespace().map(str::to_string).collect())
+				.collect(),
+		})
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, ()>(input, unexpected_answers(input).map(Err))
+	}
+	fn part_1(&self) -> Answer {
+		self.groups
+			.iter()
+			.map(|group| {
+				let mut group_answers = group.iter().fold(Vec::<u8>::new(), |mut acc, s| {
+					acc.extend(s.as_bytes());
+					acc
+				});
+				group_answers.sort_unstable();
+				group_answers.dedup();
+				group_answers.len()
+			})
+			.sum::<usize>()
+			.into()
+	}
+	fn part_2(&self) -> Answer {
+		self.groups
+			.iter()
+			.map(|group| {
+				let member_answers = group
+					.iter()
+					.map(|answers| answers.bytes().collect::<HashSet<_>>())
+					.collect::<Vec<_>>();
+				match member_answers.split_first() {
+					Some((first, rest)) => rest
+						.iter()
+						.fold(first.clone(), |intersected, members_answers| {
+							intersected.intersection(members_answers).copied().collect()
+						})
+						.len(),
+					None => 0,
+				}
+			})
+			.sum::<usize>()
+			.into()
+	}
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let groups = (0..size.max(1))
+			.map(|_| {
+				(0..rng.range(1..=5))
+					.map(|_| {
+						let mut questions = (b'a'..=b'z').map(char::from).collect::<Vec<_>>();
+						rng.shuffle(&mut questions);
+						questions[..rng.range(1..=26)].iter().collect::<String>() + "\n"
+					})
+					.collect::<String>()
+			})
+			.collect::<Vec<_>>();
+		groups.join("\n")
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		self.groups
+			.iter()
+			.map(|group| {
+				('a'..='z')
+					.filter(|question| group.iter().any(|person| person.contains(*question)))
+					.count()
+			})
+			.sum::<usize>()
+			.into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		self.groups
+			.iter()
+			.map(|group| {
+				('a'..='z')
+					.filter(|question| group.iter().all(|person| person.contains(*question)))
+					.count()
+			})
+			.sum::<usize>()
+			.into()
+	}
+}
+// Answers are the lowercase letters a to z.
+fn unexpected_answers(input: &str) -> impl Iterator<Item = ParseError> + '_ {
+	input
+		.char_indices()
+		.filter(|(_, c)| !c.is_ascii_lowercase() && !c.is_ascii_whitespace())
+		.map(move |(i, c)| {
+			ParseError::at(
+				Challenge::year(),
+				Challenge::day(),
+				input,
+				&input[i..],
+				format!("unexpected '{}'", c),
+			)
+		})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 6735);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 3221);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
@@ -0,0 +1,336 @@
+// File: year_2020/day_7.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use std::collections::{HashMap, HashSet};
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::lint;
+use crate::common::parse_error::{parse_number, ParseError};
+use crate::common::ChallengeT;
+
+pub struct Challenge {
+	parsed_input: HashMap<String, Vec<(String, u32)>>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[
+		Example::new("example", Some("4"), Some("32")),
+		Example::new("nested", None, Some("126")),
+	];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		7
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let parsed_input = input
+			.lines()
+			.map(|line| parse_line(input, line))
+			.collect::<Result<HashMap<String, Vec<(String, u32)>>, _>>()?;
+
+		let missing = parsed_input
+			.values()
+			.flatten()
+			.map(|(color, _)| color.as_str())
+			.chain(std::iter::once("shiny gold"))
+			.find(|color| !parsed_input.contains_key(*color));
+		if let Some(color) = missing {
+			return Err(ParseError::new(
+				Self::year(),
+				Self::day(),
+				0,
+				0,
+				format!("no rule for \"{}\" bags", color),
+			));
+		}
+		if let Some(line) = find_loop(input, &parsed_input) {
+			return Err(ParseError::at(
+				Self::year(),
+				Self::day(),
+				input,
+				line,
+				"the bags in this rule end up inside themselves",
+			));
+		}
+
+		Ok(Self { parsed_input })
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, _>(input, input.lines().map(|line| parse_line(input, line)))
+	}
+	fn part_1(&self) -> Answer {
+		let mut bags_that_contain_gold_bag = 0;
+		let mut cache = HashMap::new();
+		for bag in self.parsed_input.keys() {
+			if contains_gold(bag, &self.parsed_input, &mut cache) {
+				bags_that_contain_gold_bag += 1;
+			}
+		}
+		bags_that_contain_gold_bag.into()
+	}
+	fn part_2(&self) -> Answer {
+		count_bags_in("shiny gold", &self.parsed_input).into()
+	}
+	// The rules never loop and the bags inside a shiny gold bag stay countable.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		const ADJECTIVES: [&str; 16] = [
+			"bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light",
+			"mirrored", "muted", "pale", "plaid", "posh", "striped", "vibrant",
+		];
+		const COLORS: [&str; 16] = [
+			"aqua", "beige", "black", "blue", "bronze", "brown", "coral", "crimson", "gold",
+			"gray", "green", "lime", "olive", "plum", "teal", "white",
+		];
+		let mut colors = ADJECTIVES
+			.iter()
+			.flat_map(|adjective| {
+				COLORS
+					.iter()
+					.map(move |color| format!("{} {}", adjective, color))
+			})
+			.filter(|color| color != "shiny gold")
+			.collect::<Vec<_>>();
+		rng.shuffle(&mut colors);
+		colors.truncate(size.max(2).min(colors.len()) - 1);
+		colors.insert(rng.below(colors.len() + 1), String::from("shiny gold"));
+
+		// a bag only holds bags after it so the rules never loop
+		let mut inside = vec![0u64; colors.len()];
+		let mut rules = vec![String::new(); colors.len()];
+		for i in (0..colors.len()).rev() {
+			let mut contents = Vec::new();
+			for _ in 0..rng.below(5) {
+				if i + 1 == colors.len() {
+					break;
+				}
+				let j = rng.range((i + 1)..=(colors.len() - 1));
+				let count = rng.range(1..=5) as u64;
+				let more = count * (1 + inside[j]);
+				if contents.iter().any(|(k, _)| *k == j) || inside[i] + more > 100_000 {
+					continue;
+				}
+				inside[i] += more;
+				contents.push((j, count));
+			}
+			rules[i] = match contents.is_empty() {
+				true => format!("{} bags contain no other bags.\n", colors[i]),
+				false => {
+					let contents = contents
+						.iter()
+						.map(|(j, count)| {
+							let plural = if *count == 1 { "" } else { "s" };
+							format!("{} {} bag{}", count, colors[*j], plural)
+						})
+						.collect::<Vec<_>>();
+					format!("{} bags contain {}.\n", colors[i], contents.join(", "))
+				}
+			};
+		}
+		rng.shuffle(&mut rules);
+		rules.concat()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		// every bag any number of levels inside each bag
+		self.parsed_input
+			.keys()
+			.filter(|bag| {
+				let mut inside = Vec::new();
+				let mut unopened = vec![bag.as_str()];
+				while let Some(bag) = unopened.pop() {
+					for (color, _) in self.parsed_input[bag].iter() {
+						if !inside.contains(&color.as_str()) {
+							inside.push(color.as_str());
+							unopened.push(color.as_str());
+						}
+					}
+				}
+				inside.contains(&"shiny gold")
+			})
+			.count()
+			.into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		// opens every bag, with the number of bags of the same color opened together
+		let mut total = 0;
+		let mut unopened = vec![("shiny gold", 1)];
+		while let Some((bag, count)) = unopened.pop() {
+			for (color, inside) in self.parsed_input[bag].iter() {
+				total += count * inside;
+				unopened.push((color.as_str(), count * inside));
+			}
+		}
+		total.into()
+	}
+}
+
+fn parse_line(input: &str, line: &str) -> Result<(String, Vec<(String, u32)>), ParseError> {
+	let year = Challenge::year();
+	let day = Challenge::day();
+	let (color, rest) = line.split_once(" bags contain ").ok_or_else(|| {
+		ParseError::at(year, day, input, line, "expected \"<color> bags contain\"")
+	})?;
+	let mut rules = Vec::new();
+
+	if !rest.starts_with("no other bags") {
+		for rule in rest.trim_end_matches('.').split(", ") {
+			let (count, bag) = rule.split_once(' ').ok_or_else(|| {
+				ParseError::at(year, day, input, rule, "expected \"<count> <color> bags\"")
+			})?;
+			let bag_count = parse_number(year, day, input, count)?;
+
+			// remove " bags" and " bag"
+			let rule_color = bag
+				.strip_suffix(" bags")
+				.or_else(|| bag.strip_suffix(" bag"))
+				.ok_or_else(|| {
+					ParseError::at(year, day, input, bag, "expected \"<color> bags\"")
+				})?;
+			rules.push((rule_color.to_owned(), bag_count));
+		}
+	}
+
+	Ok((color.to_owned(), rules))
+}
+
+// The rule that closes the first loop found, starting from the rules in input order.
+// A bag that ends up inside itself would hold an endless number of bags.
+fn find_loop<'a>(input: &'a str, bags: &HashMap<String, Vec<(String, u32)>>) -> Option<&'a str> {
+	let color = |line: &'a str| line.split_once(" bags contain ").map(|(color, _)| color);
+	let mut finished = HashSet::new();
+	for start in input.lines().filter_map(color) {
+		// the bags being opened, each with the next bag inside it to open
+		let mut path = vec![(start, 0)];
+		while let Some(&(bag, i)) = path.last() {
+			match bags[bag].get(i) {
+				Some((inside, _)) => {
+					path.last_mut().unwrap().1 += 1;
+					if path.iter().any(|(open, _)| open == inside) {
+						return input.lines().find(|line| color(line) == Some(bag));
+					}
+					if !finished.contains(inside.as_str()) {
+						path.push((inside, 0));
+					}
+				}
+				None => {
+					finished.insert(bag);
+					path.pop();
+				}
+			}
+		}
+	}
+	None
+}
+
+fn contains_gold<'a>(
+	current: &str,
+	bags: &'a HashMap<String, Vec<(String, u32)>>,
+	cache: &mut HashMap<&'a str, bool>,
+) -> bool {
+	if let Some(val) = cache.get(current) {
+		return *val;
+	}
+
+	let current_info = bags.get(current).unwrap();
+	let current_contains_gold = current_info.iter().any(|(name, _)| *name == "shiny gold");
+
+	if current_contains_gold {
+		true
+	} else {
+		for (bag, _) in current_info {
+			if contains_gold(bag, bags, cache) {
+				cache.insert(bag, true);
+				return true;
+			} else {
+				cache.insert(bag, false);
+			}
+		}
+		false
+	}
+}
+fn count_bags_in(current: &str, bags: &HashMap<String, Vec<(String, u32)>>) -> u32 {
+	let mut count = 0;
+	for (bag_color, bags_in_bag) in bags.get(current).unwrap() {
+		count += bags_in_bag + bags_in_bag * count_bags_in(bag_color, bags);
+	}
+	count
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 151);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 41559);
+	}
+	#[test]
+	fn loop_test() {
+		let error = Challenge::from_input(
+			"shiny gold bags contain 2 dark red bags.\n\
+			 dark red bags contain 1 dark blue bag.\n\
+			 dark blue bags contain 3 dark red bags, 1 faded blue bag.\n\
+			 faded blue bags contain no other bags.",
+		)
+		.err()
+		.unwrap();
+		assert_eq!((error.day, error.line, error.column), (7, 3, 1));
+		let error = Challenge::from_input("shiny gold bags contain 1 shiny gold bag.")
+			.err()
+			.unwrap();
+		assert_eq!((error.day, error.line, error.column), (7, 1, 1));
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn part_1_bench(b: &mut Bencher) {
+		b.iter(|| Challenge::new().unwrap().part_1())
+	}
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn part_2_bench(b: &mut Bencher) {
+		b.iter(|| Challenge::new().unwrap().part_2())
+	}
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
@@ -0,0 +1,346 @@
+// File: year_2020/day_8.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::lint;
+use crate::common::parse_error::{parse_number, ParseError};
+use crate::common::ChallengeT;
+
+pub struct Challenge {
+	instructions: Vec<(InstructionType, i32)>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[Example::new("example", Some("5"), Some("8"))];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		8
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let instructions = input
+			.lines()
+			.map(|line| parse_instruction(input, line))
+			.collect::<Result<Vec<(InstructionType, i32)>, _>>()?;
+		if instructions.is_empty() {
+			return Err(ParseError::new(
+				Self::year(),
+				Self::day(),
+				1,
+				1,
+				"expected a program",
+			));
+		}
+
+		Ok(Self { instructions })
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		lint::each::<Self, _>(
+			input,
+			input.lines().map(|line| parse_instruction(input, line)),
+		)
+	}
+	fn part_1(&self) -> Answer {
+		// A program that terminates never repeats an instruction so its final acc is used.
+		match run_instructions(&self.instructions) {
+			Ok(acc) | Err(acc) => acc.into(),
+		}
+	}
+	fn part_2(&self) -> Answer {
+		let mut modified_instructions = self.instructions.clone();
+		for (i, (inst, number)) in self.instructions.iter().enumerate() {
+			match inst {
+				InstructionType::Nop => modified_instructions[i] = (InstructionType::Jmp, *number),
+				InstructionType::Jmp => modified_instructions[i] = (InstructionType::Nop, *number),
+				_ => continue,
+			};
+			if let Ok(result) = run_instructions(&modified_instructions) {
+				return result.into();
+			}
+			modified_instructions[i] = (*inst, *number);
+		}
+		Answer::NotApplicable
+	}
+	// The program loops and flipping exactly one jmp or nop makes it terminate,
+	// or now and then flipping none does.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let fixable = !rng.chance(10);
+		let instructions = loop {
+			if let Some(instructions) = generate_program(rng, size.max(2), fixable) {
+				break instructions;
+			}
+		};
+		instructions
+			.iter()
+			.map(|(inst, number)| {
+				let name = match inst {
+					InstructionType::Acc => "acc",
+					InstructionType::Jmp => "jmp",
+					InstructionType::Nop => "nop",
+				};
+				format!("{} {:+}\n", name, number)
+			})
+			.collect()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		let mut seen = vec![false; self.instructions.len()];
+		let (mut index, mut acc) = (0, 0);
+		while index < self.instructions.len() && !seen[index] {
+			seen[index] = true;
+			let (inst, number) = self.instructions[index];
+			match inst {
+				InstructionType::Acc => acc += number,
+				InstructionType::Jmp => index = (index as i32 + number - 1) as usize,
+				InstructionType::Nop => (),
+			}
+			index = index.wrapping_add(1);
+		}
+		acc.into()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		// a program that runs more instructions than it has must repeat one
+		for flip in 0..self.instructions.len() {
+			let mut instructions = self.instructions.clone();
+			instructions[flip].0 = match instructions[flip].0 {
+				InstructionType::Acc => continue,
+				InstructionType::Jmp => InstructionType::Nop,
+				InstructionType::Nop => InstructionType::Jmp,
+			};
+			let (mut index, mut acc) = (0, 0);
+			for _ in 0..=instructions.len() {
+				if index == instructions.len() {
+					return acc.into();
+				}
+				let (inst, number) = match instructions.get(index) {
+					Some(instruction) => *instruction,
+					None => break,
+				};
+				match inst {
+					InstructionType::Acc => acc += number,
+					InstructionType::Jmp => index = (index as i32 + number - 1) as usize,
+					InstructionType::Nop => (),
+				}
+				index = index.wrapping_add(1);
+			}
+		}
+		Answer::NotApplicable
+	}
+}
+
+fn parse_instruction(input: &str, line: &str) -> Result<(InstructionType, i32), ParseError> {
+	let year = Challenge::year();
+	let day = Challenge::day();
+	let (name, argument) = line.split_once(' ').ok_or_else(|| {
+		ParseError::at(
+			year,
+			day,
+			input,
+			line,
+			"expected \"<operation> <argument>\"",
+		)
+	})?;
+	let instruction_name = match name {
+		"acc" => InstructionType::Acc,
+		"jmp" => InstructionType::Jmp,
+		"nop" => InstructionType::Nop,
+		_ => {
+			return Err(ParseError::at(
+				year,
+				day,
+				input,
+				name,
+				format!("unknown operation \"{}\"", name),
+			))
+		}
+	};
+	let num = parse_number(year, day, input, argument)?;
+	Ok((instruction_name, num))
+}
+// A random program that loops or None when flipping one instruction does not fix it exactly once,
+// or when fixable is false, does not leave it unfixable.
+// Every jump, and every nop once flipped, lands inside the program or just after it.
+fn generate_program(
+	rng: &mut Rng,
+	len: usize,
+	fixable: bool,
+) -> Option<Vec<(InstructionType, i32)>> {
+	let mut instructions = (0..len)
+		.map(|i| {
+			let offset = rng.signed(-(i as i64)..=(len - i) as i64) as i32;
+			match rng.below(20) {
+				0..=8 => (InstructionType::Acc, rng.signed(-50..=50) as i32),
+				9..=15 => (InstructionType::Jmp, offset),
+				_ => (InstructionType::Nop, offset),
+			}
+		})
+		.collect::<Vec<_>>();
+	let terminates = terminating(&instructions);
+	let mut path = Vec::new();
+	let mut ran = vec![false; len];
+	let mut index = 0;
+	while index < len && !ran[index] {
+		ran[index] = true;
+		path.push(index);
+		index = next_index(index, instructions[index]);
+	}
+	if index == len {
+		return None;
+	}
+
+	// Flipping an instruction the program runs fixes it when the other way leads to the end.
+	let fixes = path
+		.into_iter()
+		.filter(|&i| {
+			let (inst, number) = instructions[i];
+			match inst {
+				InstructionType::Acc => false,
+				InstructionType::Jmp => terminates[i + 1],
+				InstructionType::Nop => terminates[(i as i32 + number) as usize],
+			}
+		})
+		.collect::<Vec<_>>();
+	if fixable && fixes.is_empty() {
+		return None;
+	}
+	let fix = if fixable {
+		Some(*rng.choose(&fixes))
+	} else {
+		None
+	};
+	for i in fixes.into_iter().filter(|i| Some(*i) != fix) {
+		// an acc goes to the same place as a nop so nothing else changes
+		match instructions[i].0 {
+			InstructionType::Nop => {
+				instructions[i] = (InstructionType::Acc, rng.signed(-50..=50) as i32)
+			}
+			_ => return None,
+		}
+	}
+	Some(instructions)
+}
+fn next_index(index: usize, (inst, number): (InstructionType, i32)) -> usize {
+	match inst {
+		InstructionType::Jmp => (index as i32 + number) as usize,
+		_ => index + 1,
+	}
+}
+// Whether running from each instruction reaches the end, which is the last element.
+fn terminating(instructions: &[(InstructionType, i32)]) -> Vec<bool> {
+	let len = instructions.len();
+	let mut terminates = vec![None; len + 1];
+	terminates[len] = Some(true);
+	let mut on_path = vec![false; len];
+	for start in 0..len {
+		let mut path = Vec::new();
+		let mut index = start;
+		let result = loop {
+			if let Some(result) = terminates[index] {
+				break result;
+			}
+			if on_path[index] {
+				break false;
+			}
+			on_path[index] = true;
+			path.push(index);
+			index = next_index(index, instructions[index]);
+		};
+		for i in path {
+			on_path[i] = false;
+			terminates[i] = Some(result);
+		}
+	}
+	terminates
+		.into_iter()
+		.map(|result| result == Some(true))
+		.collect()
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum InstructionType {
+	Acc,
+	Jmp,
+	Nop,
+}
+
+fn run_instructions(instructions: &[(InstructionType, i32)]) -> Result<i32, i32> {
+	let mut already_ran = vec![false; instructions.len()];
+	let mut acc = 0;
+	let mut index: i32 = 0;
+	loop {
+		// The program terminates by running the instruction just after the last one.
+		if index as usize >= instructions.len() {
+			return Ok(acc);
+		}
+
+		let inst_already_ran = already_ran[index as usize];
+		if inst_already_ran {
+			return Err(acc);
+		} else {
+			already_ran[index as usize] = true;
+		}
+
+		let (inst_name, inst_value) = instructions[index as usize];
+		match inst_name {
+			InstructionType::Acc => {
+				index += 1;
+				acc += inst_value;
+			}
+			InstructionType::Jmp => index += inst_value,
+			InstructionType::Nop => index += 1,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 1675);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 1532);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
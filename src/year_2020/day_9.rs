@@ -0,0 +1,294 @@
+// File: year_2020/day_9.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::common::answers::Answer;
+use crate::common::example::Example;
+use crate::common::generator::Rng;
+use crate::common::lint;
+use crate::common::parse_error::{parse_number, ParseError};
+use crate::common::ChallengeT;
+use std::str::Lines;
+
+const PREAMBLE_LEN: usize = 25;
+
+pub struct Challenge {
+	preamble_len: usize,
+	nums: Vec<usize>,
+}
+impl ChallengeT for Challenge {
+	const EXAMPLES: &'static [Example] = &[Example::new("example", Some("127"), Some("62"))];
+
+	fn year() -> u16 {
+		super::YEAR
+	}
+	fn day() -> u8 {
+		9
+	}
+	fn from_input(input: &str) -> Result<Self, ParseError> {
+		let (header, lines) = split_header(input);
+		let preamble_len =
+			header.map_or(Ok(PREAMBLE_LEN), |text| parse_preamble_len(input, text))?;
+		let nums = lines
+			.map(|line| parse_number(Self::year(), Self::day(), input, line))
+			.collect::<Result<Vec<usize>, _>>()?;
+		if nums.is_empty() {
+			return Err(ParseError::new(
+				Self::year(),
+				Self::day(),
+				1,
+				1,
+				"expected at least one number",
+			));
+		}
+
+		Ok(Self { preamble_len, nums })
+	}
+	fn lint(input: &str) -> Vec<ParseError> {
+		let (header, lines) = split_header(input);
+		let preamble = header.map(|text| parse_preamble_len(input, text).map(drop));
+		let nums = lines
+			.map(|line| parse_number::<usize>(Self::year(), Self::day(), input, line).map(drop));
+		lint::each::<Self, _>(input, preamble.into_iter().chain(nums))
+	}
+	fn part_1(&self) -> Answer {
+		invalid_number(&self.nums, self.preamble_len).map_or(Answer::NotApplicable, Answer::from)
+	}
+	fn part_2(&self) -> Answer {
+		let invalid = match invalid_number(&self.nums, self.preamble_len) {
+			Some(invalid) => invalid,
+			None => return Answer::NotApplicable,
+		};
+		for start in 0..(self.nums.len() - 1) {
+			let mut sum = self.nums[start];
+			let mut smallest = sum;
+			let mut largest = sum;
+			for end in (start + 1)..self.nums.len() {
+				let n = self.nums[end];
+				sum += n;
+				if n < smallest {
+					smallest = n;
+				} else if n > largest {
+					largest = n;
+				}
+				if sum == invalid {
+					return (smallest + largest).into();
+				}
+			}
+		}
+		Answer::NotApplicable
+	}
+	// The invalid number comes after the preamble and exactly one contiguous set
+	// of at least two numbers sums to it.
+	fn generate(rng: &mut Rng, size: usize) -> String {
+		let nums = loop {
+			if let Some(nums) = generate_numbers(rng, size.max(PREAMBLE_LEN + 2)) {
+				break nums;
+			}
+		};
+		nums.iter().map(|num| format!("{}\n", num)).collect()
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_1(&self) -> Answer {
+		reference_invalid(&self.nums, self.preamble_len).map_or(Answer::NotApplicable, Answer::from)
+	}
+	#[cfg(feature = "reference")]
+	fn reference_part_2(&self) -> Answer {
+		let invalid = match reference_invalid(&self.nums, self.preamble_len) {
+			Some(invalid) => invalid,
+			None => return Answer::NotApplicable,
+		};
+		for start in 0..self.nums.len() {
+			for end in (start + 1)..self.nums.len() {
+				let set = &self.nums[start..=end];
+				if set.iter().sum::<usize>() == invalid {
+					return (set.iter().min().unwrap() + set.iter().max().unwrap()).into();
+				}
+			}
+		}
+		Answer::NotApplicable
+	}
+}
+
+// An input may start with "preamble <length>" for a preamble other than 25, like the
+// puzzle's example which uses 5. Returns the length after "preamble " if there is one and
+// the rest of the lines, which are the numbers.
+fn split_header(input: &str) -> (Option<&str>, Lines<'_>) {
+	let mut lines = input.lines();
+	let header = input
+		.lines()
+		.next()
+		.and_then(|line| line.strip_prefix("preamble "));
+	if header.is_some() {
+		lines.next();
+	}
+	(header, lines)
+}
+fn parse_preamble_len(input: &str, text: &str) -> Result<usize, ParseError> {
+	let year = Challenge::year();
+	let day = Challenge::day();
+	let len = parse_number(year, day, input, text)?;
+	if len < 2 {
+		return Err(ParseError::at(
+			year,
+			day,
+			input,
+			text,
+			"expected a preamble of at least 2 numbers",
+		));
+	}
+	Ok(len)
+}
+
+// The first number after the preamble that is not the sum of two of the numbers before it.
+fn invalid_number(nums: &[usize], preamble_len: usize) -> Option<usize> {
+	for current in preamble_len..nums.len() {
+		let mut found_sum = false;
+		for lower in &nums[(current - preamble_len)..(current - 1)] {
+			for upper in &nums[(current - preamble_len + 1)..current] {
+				if nums[current] == lower + upper {
+					found_sum = true;
+					break;
+				}
+			}
+			if found_sum {
+				break;
+			}
+		}
+		if !found_sum {
+			return Some(nums[current]);
+		}
+	}
+	None
+}
+// The first number that is not the sum of two different numbers among the ones before it.
+#[cfg(feature = "reference")]
+fn reference_invalid(nums: &[usize], preamble_len: usize) -> Option<usize> {
+	(preamble_len..nums.len())
+		.find(|&i| {
+			let window = &nums[(i - preamble_len)..i];
+			!window
+				.iter()
+				.any(|a| window.iter().any(|b| a != b && a + b == nums[i]))
+		})
+		.map(|i| nums[i])
+}
+
+// Each valid number is the sum of two of the smallest numbers before it so they grow slowly.
+// None when the numbers can not all differ or more than one set sums to the invalid number.
+fn generate_numbers(rng: &mut Rng, len: usize) -> Option<Vec<usize>> {
+	// large numbers so a set rarely sums to the invalid number by chance
+	let mut nums = Vec::with_capacity(len);
+	while nums.len() < PREAMBLE_LEN {
+		let num = rng.range(1..=1_000_000_000);
+		if !nums.contains(&num) {
+			nums.push(num);
+		}
+	}
+	let invalid_at = rng.range((PREAMBLE_LEN + 1)..=(len - 1));
+	while nums.len() < len {
+		let window = &nums[(nums.len() - PREAMBLE_LEN)..];
+		let num = if nums.len() == invalid_at {
+			let start = rng.below(invalid_at - 1);
+			let end = rng.range((start + 1)..=(invalid_at - 1));
+			let target = nums[start..=end].iter().sum::<usize>();
+			let valid = window
+				.iter()
+				.any(|a| target.checked_sub(*a).is_some_and(|b| window.contains(&b)));
+			if valid || nums.contains(&target) {
+				return None;
+			}
+			target
+		} else {
+			let mut smallest = window.to_vec();
+			smallest.sort_unstable();
+			let mut pairs = (0..5)
+				.flat_map(|a| ((a + 1)..5).map(move |b| (a, b)))
+				.collect::<Vec<_>>();
+			rng.shuffle(&mut pairs);
+			pairs
+				.iter()
+				.map(|(a, b)| smallest[*a] + smallest[*b])
+				.find(|num| !nums.contains(num))?
+		};
+		nums.push(num);
+	}
+
+	let target = nums[invalid_at];
+	let mut sets = 0;
+	for start in 0..len {
+		let mut sum = nums[start];
+		for num in nums[(start + 1)..].iter() {
+			sum += num;
+			if sum >= target {
+				sets += (sum == target) as usize;
+				break;
+			}
+		}
+	}
+	if sets == 1 {
+		Some(nums)
+	} else {
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Challenge;
+	use crate::common::ChallengeT;
+	#[cfg(feature = "nightly")]
+	use test::Bencher;
+
+	#[test]
+	fn part_1_test() {
+		assert_eq!(Challenge::new().unwrap().part_1(), 15690279);
+	}
+	#[test]
+	fn part_2_test() {
+		assert_eq!(Challenge::new().unwrap().part_2(), 2_174_232);
+	}
+	#[test]
+	fn preamble_lint_test() {
+		let positions = |input| {
+			Challenge::lint(input)
+				.iter()
+				.map(|problem| (problem.line, problem.column))
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(positions("preamble five\n1\n2\nx3"), [(1, 10), (4, 1)]);
+		assert_eq!(positions("preamble 1\n1\n2\n3"), [(1, 10)]);
+		assert_eq!(positions("preamble 2\n1\n2\n3"), []);
+	}
+
+	#[cfg(feature = "nightly")]
+	#[bench]
+	fn both(b: &mut Bencher) {
+		b.iter(|| {
+			let challenge = Challenge::new().unwrap();
+			challenge.part_1();
+			challenge.part_2();
+		})
+	}
+}
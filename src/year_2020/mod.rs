@@ -0,0 +1,81 @@
+// File: year_2020/mod.rs
+// Author: Jacob Guenther
+// Date: December 2020
+
+/*
+Copyright 2020 Jacob Guenther
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of
+this software and associated documentation files (the "Software"), to deal in
+the Software without restriction, including without limitation the rights to
+use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+the Software, and to permit persons to whom the Software is furnished to do so,
+subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+use crate::common::registry::Entry;
+
+pub const YEAR: u16 = 2020;
+
+pub mod day_1;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_2;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_25;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+
+pub static DAYS: &[Entry] = &[
+	Entry::new::<day_1::Challenge>(YEAR, 1, "Report Repair"),
+	Entry::new::<day_2::Challenge>(YEAR, 2, "Password Philosophy"),
+	Entry::new::<day_3::Challenge>(YEAR, 3, "Toboggan Trajectory"),
+	Entry::new::<day_4::Challenge>(YEAR, 4, "Passport Processing"),
+	Entry::new::<day_5::Challenge>(YEAR, 5, "Binary Boarding"),
+	Entry::new::<day_6::Challenge>(YEAR, 6, "Custom Customs"),
+	Entry::new::<day_7::Challenge>(YEAR, 7, "Handy Haversacks"),
+	Entry::new::<day_8::Challenge>(YEAR, 8, "Handheld Halting"),
+	Entry::new::<day_9::Challenge>(YEAR, 9, "Encoding Error"),
+	Entry::new::<day_10::Challenge>(YEAR, 10, "Adapter Array"),
+	Entry::new::<day_11::Challenge>(YEAR, 11, "Seating System"),
+	Entry::new::<day_12::Challenge>(YEAR, 12, "Rain Risk"),
+	Entry::new::<day_13::Challenge>(YEAR, 13, "Shuttle Search"),
+	Entry::new::<day_14::Challenge>(YEAR, 14, "Docking Data"),
+	Entry::new::<day_15::Challenge>(YEAR, 15, "Rambunctious Recitation"),
+	Entry::new::<day_16::Challenge>(YEAR, 16, "Ticket Translation"),
+	Entry::new::<day_17::Challenge>(YEAR, 17, "Conway Cubes"),
+	Entry::new::<day_18::Challenge>(YEAR, 18, "Operation Order"),
+	Entry::new::<day_19::Challenge>(YEAR, 19, "Monster Messages"),
+	Entry::new::<day_20::Challenge>(YEAR, 20, "Jurassic Jigsaw"),
+	Entry::new::<day_21::Challenge>(YEAR, 21, "Allergen Assessment"),
+	Entry::new::<day_22::Challenge>(YEAR, 22, "Crab Combat"),
+	Entry::new::<day_23::Challenge>(YEAR, 23, "Crab Cups"),
+	Entry::new::<day_24::Challenge>(YEAR, 24, "Lobby Layout"),
+	Entry::new::<day_25::Challenge>(YEAR, 25, "Combo Breaker"),
+];